@@ -0,0 +1,203 @@
+use crate::{Float, Real};
+
+use std::{fmt, ops};
+
+// Angles in radians and degrees. Every angle-taking function accepts either
+// through `impl Into<Rad<T>>`, so bare numbers can't be mixed up.
+#[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd)]
+#[repr(transparent)]
+pub struct Rad<T = Float>(pub T);
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd)]
+#[repr(transparent)]
+pub struct Deg<T = Float>(pub T);
+
+// Wraps `value` into `[0, turn)`, or into `(-turn / 2, turn / 2]` if `signed`.
+fn wrap<T: Real>(value: T, turn: T, signed: bool) -> T {
+	let mut v = value.rem_euclid(turn);
+	// `rem_euclid` can round up to `turn` itself for tiny negative values.
+	if v >= turn {
+		v = T::ZERO;
+	}
+	if signed && v > turn / (T::ONE + T::ONE) {
+		v -= turn;
+	}
+	v
+}
+
+impl<T: Real> Rad<T> {
+	pub fn full_turn() -> Self {
+		Self(T::from_f64(std::f64::consts::TAU))
+	}
+	pub fn half_turn() -> Self {
+		Self(T::from_f64(std::f64::consts::PI))
+	}
+	// Wraps into `[0, 2π)`.
+	pub fn normalized(self) -> Self {
+		Self(wrap(self.0, Self::full_turn().0, false))
+	}
+	// Wraps into `(-π, π]`.
+	pub fn normalized_signed(self) -> Self {
+		Self(wrap(self.0, Self::full_turn().0, true))
+	}
+	pub fn sin(self) -> T {
+		self.0.sin()
+	}
+	pub fn cos(self) -> T {
+		self.0.cos()
+	}
+	pub fn tan(self) -> T {
+		self.0.tan()
+	}
+	pub fn sin_cos(self) -> (T, T) {
+		(self.0.sin(), self.0.cos())
+	}
+	pub fn asin(v: T) -> Self {
+		Self(v.asin())
+	}
+	pub fn acos(v: T) -> Self {
+		Self(v.acos())
+	}
+	pub fn atan2(y: T, x: T) -> Self {
+		Self(y.atan2(x))
+	}
+}
+
+impl<T: Real> Deg<T> {
+	pub fn full_turn() -> Self {
+		Self(T::from_f64(360.0))
+	}
+	pub fn half_turn() -> Self {
+		Self(T::from_f64(180.0))
+	}
+	// Wraps into `[0, 360)`.
+	pub fn normalized(self) -> Self {
+		Self(wrap(self.0, Self::full_turn().0, false))
+	}
+	// Wraps into `(-180, 180]`.
+	pub fn normalized_signed(self) -> Self {
+		Self(wrap(self.0, Self::full_turn().0, true))
+	}
+	pub fn sin(self) -> T {
+		Rad::from(self).sin()
+	}
+	pub fn cos(self) -> T {
+		Rad::from(self).cos()
+	}
+	pub fn tan(self) -> T {
+		Rad::from(self).tan()
+	}
+	pub fn sin_cos(self) -> (T, T) {
+		Rad::from(self).sin_cos()
+	}
+	pub fn asin(v: T) -> Self {
+		Rad::asin(v).into()
+	}
+	pub fn acos(v: T) -> Self {
+		Rad::acos(v).into()
+	}
+	pub fn atan2(y: T, x: T) -> Self {
+		Rad::atan2(y, x).into()
+	}
+}
+
+impl<T: Real> From<Deg<T>> for Rad<T> {
+	fn from(d: Deg<T>) -> Self {
+		Self(d.0 * Rad::half_turn().0 / Deg::half_turn().0)
+	}
+}
+
+impl<T: Real> From<Rad<T>> for Deg<T> {
+	fn from(r: Rad<T>) -> Self {
+		Self(r.0 * Deg::half_turn().0 / Rad::half_turn().0)
+	}
+}
+
+impl<T: Real> fmt::Display for Rad<T> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		fmt::Display::fmt(&self.0, f)?;
+		write!(f, " rad")
+	}
+}
+
+impl<T: Real> fmt::Display for Deg<T> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		fmt::Display::fmt(&self.0, f)?;
+		write!(f, "°")
+	}
+}
+
+macro_rules! angle_ops {
+	($($angle:ident),*) => {
+		$(
+			impl<T: Real> ops::Neg for $angle<T> {
+				type Output = Self;
+				fn neg(self) -> Self::Output {
+					Self(-self.0)
+				}
+			}
+
+			impl<T: Real> ops::Add for $angle<T> {
+				type Output = Self;
+				fn add(self, other: Self) -> Self::Output {
+					Self(self.0 + other.0)
+				}
+			}
+
+			impl<T: Real> ops::Sub for $angle<T> {
+				type Output = Self;
+				fn sub(self, other: Self) -> Self::Output {
+					Self(self.0 - other.0)
+				}
+			}
+
+			impl<T: Real> ops::Mul<T> for $angle<T> {
+				type Output = Self;
+				fn mul(self, scalar: T) -> Self::Output {
+					Self(self.0 * scalar)
+				}
+			}
+
+			impl<T: Real> ops::Div<T> for $angle<T> {
+				type Output = Self;
+				fn div(self, scalar: T) -> Self::Output {
+					Self(self.0 / scalar)
+				}
+			}
+
+			// The ratio of two angles.
+			impl<T: Real> ops::Div for $angle<T> {
+				type Output = T;
+				fn div(self, other: Self) -> Self::Output {
+					self.0 / other.0
+				}
+			}
+
+			impl<T: Real> ops::AddAssign for $angle<T> {
+				fn add_assign(&mut self, other: Self) {
+					self.0 += other.0;
+				}
+			}
+
+			impl<T: Real> ops::SubAssign for $angle<T> {
+				fn sub_assign(&mut self, other: Self) {
+					self.0 -= other.0;
+				}
+			}
+
+			impl<T: Real> ops::MulAssign<T> for $angle<T> {
+				fn mul_assign(&mut self, scalar: T) {
+					self.0 *= scalar;
+				}
+			}
+
+			impl<T: Real> ops::DivAssign<T> for $angle<T> {
+				fn div_assign(&mut self, scalar: T) {
+					self.0 /= scalar;
+				}
+			}
+		)*
+	};
+}
+
+angle_ops!(Rad, Deg);
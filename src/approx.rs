@@ -0,0 +1,238 @@
+use crate::{DMatrix, DVector, Deg, Matrix, Point, Quaternion, Rad, Scalar, Vector};
+
+// Approximate equality for floating point values and everything built out of
+// them.
+pub trait ApproxEq {
+	type Epsilon: Copy;
+	fn default_epsilon() -> Self::Epsilon;
+	fn default_max_relative() -> Self::Epsilon;
+	fn default_max_ulps() -> u32 {
+		4
+	}
+	// `|a - b| <= epsilon`
+	fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool;
+	// `|a - b| <= epsilon`, or `|a - b| <= max(|a|, |b|) * max_relative`.
+	fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool;
+	// `|a - b| <= epsilon`, or `a` and `b` are at most `max_ulps`
+	// representable values apart.
+	fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool;
+}
+
+macro_rules! for_all_float_types {
+	($t:ty, $bits:ty) => {
+		impl ApproxEq for $t {
+			type Epsilon = $t;
+			fn default_epsilon() -> $t {
+				<$t>::EPSILON
+			}
+			fn default_max_relative() -> $t {
+				<$t>::EPSILON
+			}
+			fn abs_diff_eq(&self, other: &$t, epsilon: $t) -> bool {
+				self == other || (self - other).abs() <= epsilon
+			}
+			fn relative_eq(&self, other: &$t, epsilon: $t, max_relative: $t) -> bool {
+				if self == other {
+					return true;
+				}
+				if self.is_infinite() || other.is_infinite() {
+					return false;
+				}
+				let diff = (self - other).abs();
+				diff <= epsilon || diff <= self.abs().max(other.abs()) * max_relative
+			}
+			fn ulps_eq(&self, other: &$t, epsilon: $t, max_ulps: u32) -> bool {
+				if self.abs_diff_eq(other, epsilon) {
+					return true;
+				}
+				// Infinities are only equal to themselves, not to their neighbour `MAX`.
+				if !self.is_finite() || !other.is_finite() || self.is_sign_negative() != other.is_sign_negative() {
+					return false;
+				}
+				let (a, b) = (self.to_bits() as $bits, other.to_bits() as $bits);
+				(a - b).unsigned_abs() <= max_ulps as _
+			}
+		}
+	};
+}
+
+for_all_float_types!(f32, i32);
+for_all_float_types!(f64, i64);
+
+impl<T: ApproxEq> ApproxEq for [T] {
+	type Epsilon = T::Epsilon;
+	fn default_epsilon() -> T::Epsilon {
+		T::default_epsilon()
+	}
+	fn default_max_relative() -> T::Epsilon {
+		T::default_max_relative()
+	}
+	fn default_max_ulps() -> u32 {
+		T::default_max_ulps()
+	}
+	fn abs_diff_eq(&self, other: &Self, epsilon: T::Epsilon) -> bool {
+		self.len() == other.len() && self.iter().zip(other).all(|(a, b)| a.abs_diff_eq(b, epsilon))
+	}
+	fn relative_eq(&self, other: &Self, epsilon: T::Epsilon, max_relative: T::Epsilon) -> bool {
+		self.len() == other.len() && self.iter().zip(other).all(|(a, b)| a.relative_eq(b, epsilon, max_relative))
+	}
+	fn ulps_eq(&self, other: &Self, epsilon: T::Epsilon, max_ulps: u32) -> bool {
+		self.len() == other.len() && self.iter().zip(other).all(|(a, b)| a.ulps_eq(b, epsilon, max_ulps))
+	}
+}
+
+// Composite types compare as the slice of their elements.
+macro_rules! elementwise {
+	($(impl<$($n:ident),*> for $ty:ty;)*) => {
+		$(
+			impl<$(const $n: usize,)* T: Scalar + ApproxEq> ApproxEq for $ty {
+				type Epsilon = T::Epsilon;
+				fn default_epsilon() -> T::Epsilon {
+					T::default_epsilon()
+				}
+				fn default_max_relative() -> T::Epsilon {
+					T::default_max_relative()
+				}
+				fn default_max_ulps() -> u32 {
+					T::default_max_ulps()
+				}
+				fn abs_diff_eq(&self, other: &Self, epsilon: T::Epsilon) -> bool {
+					self.as_slice().abs_diff_eq(other.as_slice(), epsilon)
+				}
+				fn relative_eq(&self, other: &Self, epsilon: T::Epsilon, max_relative: T::Epsilon) -> bool {
+					self.as_slice().relative_eq(other.as_slice(), epsilon, max_relative)
+				}
+				fn ulps_eq(&self, other: &Self, epsilon: T::Epsilon, max_ulps: u32) -> bool {
+					self.as_slice().ulps_eq(other.as_slice(), epsilon, max_ulps)
+				}
+			}
+		)*
+	};
+}
+
+elementwise! {
+	impl<N> for Vector<N, T>;
+	impl<N> for Point<N, T>;
+	impl<R, C> for Matrix<R, C, T>;
+	impl<> for Quaternion<T>;
+	impl<> for DVector<T>;
+}
+
+macro_rules! angles {
+	($($angle:ident),*) => {
+		$(
+			impl<T: ApproxEq> ApproxEq for $angle<T> {
+				type Epsilon = T::Epsilon;
+				fn default_epsilon() -> T::Epsilon {
+					T::default_epsilon()
+				}
+				fn default_max_relative() -> T::Epsilon {
+					T::default_max_relative()
+				}
+				fn default_max_ulps() -> u32 {
+					T::default_max_ulps()
+				}
+				fn abs_diff_eq(&self, other: &Self, epsilon: T::Epsilon) -> bool {
+					self.0.abs_diff_eq(&other.0, epsilon)
+				}
+				fn relative_eq(&self, other: &Self, epsilon: T::Epsilon, max_relative: T::Epsilon) -> bool {
+					self.0.relative_eq(&other.0, epsilon, max_relative)
+				}
+				fn ulps_eq(&self, other: &Self, epsilon: T::Epsilon, max_ulps: u32) -> bool {
+					self.0.ulps_eq(&other.0, epsilon, max_ulps)
+				}
+			}
+		)*
+	};
+}
+
+angles!(Rad, Deg);
+
+// Matrices of different shapes are never equal, even with the same elements.
+impl<T: Scalar + ApproxEq> ApproxEq for DMatrix<T> {
+	type Epsilon = T::Epsilon;
+	fn default_epsilon() -> T::Epsilon {
+		T::default_epsilon()
+	}
+	fn default_max_relative() -> T::Epsilon {
+		T::default_max_relative()
+	}
+	fn default_max_ulps() -> u32 {
+		T::default_max_ulps()
+	}
+	fn abs_diff_eq(&self, other: &Self, epsilon: T::Epsilon) -> bool {
+		self.width() == other.width() && self.as_slice().abs_diff_eq(other.as_slice(), epsilon)
+	}
+	fn relative_eq(&self, other: &Self, epsilon: T::Epsilon, max_relative: T::Epsilon) -> bool {
+		self.width() == other.width() && self.as_slice().relative_eq(other.as_slice(), epsilon, max_relative)
+	}
+	fn ulps_eq(&self, other: &Self, epsilon: T::Epsilon, max_ulps: u32) -> bool {
+		self.width() == other.width() && self.as_slice().ulps_eq(other.as_slice(), epsilon, max_ulps)
+	}
+}
+
+// Used by the assertion macros below, where the defaults can only be found
+// through the compared values' type.
+#[doc(hidden)]
+pub fn __abs_diff_eq<T: ApproxEq + ?Sized>(a: &T, b: &T, epsilon: Option<T::Epsilon>) -> bool {
+	a.abs_diff_eq(b, epsilon.unwrap_or_else(T::default_epsilon))
+}
+
+#[doc(hidden)]
+pub fn __relative_eq<T: ApproxEq + ?Sized>(a: &T, b: &T, epsilon: Option<T::Epsilon>, max_relative: Option<T::Epsilon>) -> bool {
+	a.relative_eq(b, epsilon.unwrap_or_else(T::default_epsilon), max_relative.unwrap_or_else(T::default_max_relative))
+}
+
+#[doc(hidden)]
+pub fn __ulps_eq<T: ApproxEq + ?Sized>(a: &T, b: &T, epsilon: Option<T::Epsilon>, max_ulps: Option<u32>) -> bool {
+	a.ulps_eq(b, epsilon.unwrap_or_else(T::default_epsilon), max_ulps.unwrap_or_else(T::default_max_ulps))
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __approx_arg {
+	() => {
+		None
+	};
+	($value:expr) => {
+		Some($value)
+	};
+}
+
+// `assert_approx_eq!(a, b)` or `assert_approx_eq!(a, b, epsilon = 1e-6)`
+#[macro_export]
+macro_rules! assert_approx_eq {
+	($a:expr, $b:expr $(, epsilon = $epsilon:expr)? $(,)?) => {
+		match (&$a, &$b) {
+			(a, b) => if !$crate::__abs_diff_eq(a, b, $crate::__approx_arg!($($epsilon)?)) {
+				panic!("assertion failed: `{} ≈ {}`\n  left: {:?}\n right: {:?}", stringify!($a), stringify!($b), a, b);
+			}
+		}
+	};
+}
+
+// `assert_relative_eq!(a, b)`, optionally followed by `epsilon = ..` and/or
+// `max_relative = ..`
+#[macro_export]
+macro_rules! assert_relative_eq {
+	($a:expr, $b:expr $(, epsilon = $epsilon:expr)? $(, max_relative = $max_relative:expr)? $(,)?) => {
+		match (&$a, &$b) {
+			(a, b) => if !$crate::__relative_eq(a, b, $crate::__approx_arg!($($epsilon)?), $crate::__approx_arg!($($max_relative)?)) {
+				panic!("assertion failed: `{} ≈ {}` (relative)\n  left: {:?}\n right: {:?}", stringify!($a), stringify!($b), a, b);
+			}
+		}
+	};
+}
+
+// `assert_ulps_eq!(a, b)`, optionally followed by `epsilon = ..` and/or
+// `max_ulps = ..`
+#[macro_export]
+macro_rules! assert_ulps_eq {
+	($a:expr, $b:expr $(, epsilon = $epsilon:expr)? $(, max_ulps = $max_ulps:expr)? $(,)?) => {
+		match (&$a, &$b) {
+			(a, b) => if !$crate::__ulps_eq(a, b, $crate::__approx_arg!($($epsilon)?), $crate::__approx_arg!($($max_ulps)?)) {
+				panic!("assertion failed: `{} ≈ {}` (ulps)\n  left: {:?}\n right: {:?}", stringify!($a), stringify!($b), a, b);
+			}
+		}
+	};
+}
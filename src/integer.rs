@@ -1,3 +1,4 @@
+#[allow(clippy::wrong_self_convention)]
 pub trait Integer {
 	fn factorial(self) -> Self;
 	fn permutations(n: Self, k: Self) -> Self;
@@ -34,13 +35,13 @@ macro_rules! for_all_integer_types {
 
 				while exp > 1 {
 					if (exp & 1) == 1 {
-						acc = acc * base;
+						acc *= base;
 					}
 					exp /= 2;
 					base = base * base;
 				}
 				if exp == 1 {
-					acc = acc * base;
+					acc *= base;
 				}
 
 				acc
@@ -3,13 +3,29 @@ pub type Float = f64;
 #[cfg(not(feature = "big-floats"))]
 pub type Float = f32;
 
+mod scalar;
 mod integer;
 mod vector;
+mod point;
 mod matrix;
+mod quaternion;
+mod angle;
+mod approx;
+mod parse;
+#[cfg(feature = "serde")]
+mod serialize;
+#[cfg(feature = "bytemuck")]
+mod pod;
 
+pub use scalar::*;
 pub use integer::*;
 pub use vector::*;
+pub use point::*;
 pub use matrix::*;
+pub use quaternion::*;
+pub use angle::*;
+pub use approx::*;
+pub use parse::*;
 
 #[cfg(test)]
 mod tests;
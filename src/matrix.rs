@@ -1,19 +1,389 @@
 mod matrix3x4;
 mod matrix3x3;
-mod matrix2x2;
 mod matrix4x4;
+mod solve;
+mod decompose;
+mod eigen;
+mod svd;
+mod dmatrix;
+mod sparse;
+mod format;
 
-pub use matrix3x4::*;
-pub use matrix3x3::*;
-pub use matrix2x2::*;
-pub use matrix4x4::*;
+pub use solve::*;
+pub use decompose::*;
+pub use eigen::*;
+pub use svd::*;
+pub use dmatrix::*;
+pub use sparse::*;
+pub use format::{Latex, Markdown};
 
-use crate::Float;
+use crate::{Float, Real, Scalar, Vector};
 
-pub trait Matrix {
+use std::{fmt, ops};
+
+pub trait MatrixOps {
+	type Scalar: crate::Scalar;
+	type Row;
+	type Col;
+	type Rows;
+	type Cols;
+	type Transpose;
 	fn zero() -> Self;
 	fn identity() -> Self;
 	fn width() -> usize;
 	fn height() -> usize;
-	fn det(&self) -> Float;
+	fn row(&self, r: usize) -> Self::Row;
+	fn col(&self, c: usize) -> Self::Col;
+	fn from_rows(rows: Self::Rows) -> Self;
+	fn from_cols(cols: Self::Cols) -> Self;
+	fn transpose(&self) -> Self::Transpose;
+}
+
+pub trait SquareMatrix: MatrixOps {
+	type Minor;
+	fn det(&self) -> Self::Scalar;
+	fn trace(&self) -> Self::Scalar;
+	// The matrix with row `r` and column `c` removed.
+	fn minor(&self, r: usize, c: usize) -> Self::Minor;
+	fn cofactor(&self, r: usize, c: usize) -> Self::Scalar;
+	fn cofactor_matrix(&self) -> Self;
+	fn adjugate(&self) -> Self;
+}
+
+// `R` rows of `C` columns each, laid out in memory as `R * C` contiguous
+// scalars in row-major order.
+#[derive(Clone, Copy, PartialEq)]
+#[repr(transparent)]
+pub struct Matrix<const R: usize, const C: usize, T = Float>(pub(crate) [Vector<C, T>; R]);
+
+pub type Matrix2x2<T = Float> = Matrix<2, 2, T>;
+pub type Matrix2x3<T = Float> = Matrix<2, 3, T>;
+pub type Matrix2x4<T = Float> = Matrix<2, 4, T>;
+pub type Matrix3x2<T = Float> = Matrix<3, 2, T>;
+pub type Matrix3x3<T = Float> = Matrix<3, 3, T>;
+pub type Matrix3x4<T = Float> = Matrix<3, 4, T>;
+pub type Matrix4x2<T = Float> = Matrix<4, 2, T>;
+pub type Matrix4x3<T = Float> = Matrix<4, 3, T>;
+pub type Matrix4x4<T = Float> = Matrix<4, 4, T>;
+pub type Matrix4x5<T = Float> = Matrix<4, 5, T>;
+
+// Constructors without arguments are only inherent for `Float`, so that
+// `Matrix3x3::identity()` still infers its scalar type. Other scalar types
+// get them through `MatrixOps`.
+impl<const R: usize, const C: usize> Matrix<R, C> {
+	pub const fn zero() -> Self {
+		Self([Vector::zero(); R])
+	}
+	pub const fn identity() -> Self {
+		let mut m = Self::zero();
+		let mut i = 0;
+		while i < R && i < C {
+			m.0[i].0[i] = 1.0;
+			i += 1;
+		}
+		m
+	}
+	pub const fn width() -> usize {
+		C
+	}
+	pub const fn height() -> usize {
+		R
+	}
+}
+
+impl<const R: usize, const C: usize, T: Scalar> Matrix<R, C, T> {
+	pub fn from_cols_array(cols: [[T; R]; C]) -> Self {
+		Self(std::array::from_fn(|r| Vector(std::array::from_fn(|c| cols[c][r]))))
+	}
+	pub fn to_cols_array(&self) -> [[T; R]; C] {
+		std::array::from_fn(|c| std::array::from_fn(|r| self[r][c]))
+	}
+	pub fn to_rows_array(&self) -> [[T; C]; R] {
+		self.0.map(|row| row.0)
+	}
+	pub fn as_slice(&self) -> &[T] {
+		// `Vector` is a transparent wrapper around `[T; C]`.
+		unsafe { std::slice::from_raw_parts(self.0.as_ptr() as *const T, R * C) }
+	}
+	pub fn as_mut_slice(&mut self) -> &mut [T] {
+		unsafe { std::slice::from_raw_parts_mut(self.0.as_mut_ptr() as *mut T, R * C) }
+	}
+	pub fn transpose(&self) -> Matrix<C, R, T> {
+		Matrix(std::array::from_fn(|c| Vector(std::array::from_fn(|r| self[r][c]))))
+	}
+	fn without<const R2: usize, const C2: usize>(&self, r: usize, c: usize) -> Matrix<R2, C2, T> {
+		Matrix(std::array::from_fn(|i| {
+			let i = if i < r { i } else { i + 1 };
+			Vector(std::array::from_fn(|j| self[i][if j < c { j } else { j + 1 }]))
+		}))
+	}
+	fn norm_inf(&self) -> T {
+		(0..R)
+			.map(|r| (0..C).map(|c| self[r][c].abs()).sum())
+			.fold(T::ZERO, |max, v| if v > max { v } else { max })
+	}
+}
+
+impl<const R: usize, const C: usize, T: Scalar> MatrixOps for Matrix<R, C, T> {
+	type Scalar = T;
+	type Row = Vector<C, T>;
+	type Col = Vector<R, T>;
+	type Rows = [Vector<C, T>; R];
+	type Cols = [Vector<R, T>; C];
+	type Transpose = Matrix<C, R, T>;
+
+	fn zero() -> Self {
+		Self([Vector::splat(T::ZERO); R])
+	}
+	fn identity() -> Self {
+		let mut m = <Self as MatrixOps>::zero();
+		for i in 0..R.min(C) {
+			m[i][i] = T::ONE;
+		}
+		m
+	}
+	fn width() -> usize {
+		C
+	}
+	fn height() -> usize {
+		R
+	}
+	fn row(&self, r: usize) -> Self::Row {
+		self[r]
+	}
+	fn col(&self, c: usize) -> Self::Col {
+		Vector(std::array::from_fn(|r| self[r][c]))
+	}
+	fn from_rows(rows: Self::Rows) -> Self {
+		Self(rows)
+	}
+	fn from_cols(cols: Self::Cols) -> Self {
+		Matrix(cols).transpose()
+	}
+	fn transpose(&self) -> Self::Transpose {
+		Self::transpose(self)
+	}
+}
+
+impl<T: Scalar> SquareMatrix for Matrix<1, 1, T> {
+	type Minor = Matrix<0, 0, T>;
+
+	fn det(&self) -> T {
+		self[0][0]
+	}
+	fn trace(&self) -> T {
+		self[0][0]
+	}
+	fn minor(&self, r: usize, c: usize) -> Self::Minor {
+		self.without(r, c)
+	}
+	fn cofactor(&self, _: usize, _: usize) -> T {
+		T::ONE
+	}
+	fn cofactor_matrix(&self) -> Self {
+		Self::identity()
+	}
+	fn adjugate(&self) -> Self {
+		Self::identity()
+	}
+}
+
+macro_rules! square_matrix {
+	($n:expr, $minor:expr) => {
+		impl<T: Scalar> SquareMatrix for Matrix<$n, $n, T> {
+			type Minor = Matrix<$minor, $minor, T>;
+
+			fn det(&self) -> T {
+				(0..$n).map(|c| self[0][c] * self.cofactor(0, c)).sum()
+			}
+			fn trace(&self) -> T {
+				(0..$n).map(|i| self[i][i]).sum()
+			}
+			fn minor(&self, r: usize, c: usize) -> Self::Minor {
+				self.without(r, c)
+			}
+			fn cofactor(&self, r: usize, c: usize) -> T {
+				let minor = self.minor(r, c).det();
+				if (r + c) % 2 == 1 { -minor } else { minor }
+			}
+			fn cofactor_matrix(&self) -> Self {
+				Matrix(std::array::from_fn(|r| Vector(std::array::from_fn(|c| self.cofactor(r, c)))))
+			}
+			fn adjugate(&self) -> Self {
+				self.cofactor_matrix().transpose()
+			}
+		}
+	};
+}
+
+square_matrix!(2, 1);
+square_matrix!(3, 2);
+square_matrix!(4, 3);
+
+impl<const N: usize, T: Real> Matrix<N, N, T>
+where
+	Self: SquareMatrix<Scalar = T>,
+{
+	pub fn inverse(&self) -> Option<Self> {
+		if decompose::singular(self.as_slice(), N) {
+			return None;
+		}
+		// Rows are scaled to a largest element of 1 first, so the determinant
+		// can't under- or overflow.
+		let scale: [T; N] = std::array::from_fn(|r| self[r].0.iter().fold(T::ZERO, |max, v| max.max(v.abs())));
+		let scaled = Matrix(std::array::from_fn(|r| self[r] / scale[r]));
+		let mut inv = scaled.adjugate() * (T::ONE / scaled.det());
+		for r in 0..N {
+			for c in 0..N {
+				inv[r][c] /= scale[c];
+			}
+		}
+		Some(inv)
+	}
+	pub fn invert(&mut self) -> bool {
+		match self.inverse() {
+			Some(inv) => {
+				*self = inv;
+				true
+			}
+			None => false,
+		}
+	}
+	// The condition number is measured in the infinity norm.
+	pub fn try_inverse(&self) -> Option<(Self, T)> {
+		let inv = self.inverse()?;
+		let cond = self.norm_inf() * inv.norm_inf();
+		Some((inv, cond))
+	}
+}
+
+impl<const R: usize, const C: usize, T: Scalar> From<[Vector<C, T>; R]> for Matrix<R, C, T> {
+	fn from(v: [Vector<C, T>; R]) -> Self {
+		Self(v)
+	}
+}
+
+impl<const R: usize, const C: usize, T: Scalar> From<[[T; C]; R]> for Matrix<R, C, T> {
+	fn from(v: [[T; C]; R]) -> Self {
+		Self(v.map(Vector::from))
+	}
+}
+
+macro_rules! from_row_major {
+	($r:expr, $c:expr) => {
+		impl<T: Scalar> From<[T; $c*$r]> for Matrix<$r, $c, T> {
+			fn from(v: [T; $c*$r]) -> Self {
+				Self(std::array::from_fn(|r| Vector(std::array::from_fn(|c| v[r * $c + c]))))
+			}
+		}
+	};
+}
+
+from_row_major!(2, 2);
+from_row_major!(2, 3);
+from_row_major!(2, 4);
+from_row_major!(3, 2);
+from_row_major!(3, 3);
+from_row_major!(3, 4);
+from_row_major!(4, 2);
+from_row_major!(4, 3);
+from_row_major!(4, 4);
+from_row_major!(4, 5);
+
+impl<const R: usize, const C: usize, T: Scalar> ops::Index<usize> for Matrix<R, C, T> {
+	type Output = Vector<C, T>;
+	fn index(&self, i: usize) -> &Self::Output {
+		&self.0[i]
+	}
+}
+
+impl<const R: usize, const C: usize, T: Scalar> ops::IndexMut<usize> for Matrix<R, C, T> {
+	fn index_mut(&mut self, i: usize) -> &mut Self::Output {
+		&mut self.0[i]
+	}
+}
+
+impl<const R: usize, const C: usize, T: Scalar> fmt::Debug for Matrix<R, C, T> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		format::fmt_debug(f, R, C, self.as_slice())
+	}
+}
+
+impl<const R: usize, const C: usize, T: Scalar> fmt::Display for Matrix<R, C, T> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		format::fmt_display(f, R, C, self.as_slice())
+	}
+}
+
+impl<const R: usize, const C: usize, T: Scalar> ops::Neg for Matrix<R, C, T> {
+	type Output = Self;
+	fn neg(self) -> Self::Output {
+		Self(self.0.map(|row| -row))
+	}
+}
+
+impl<const R: usize, const C: usize, T: Scalar> ops::Add for Matrix<R, C, T> {
+	type Output = Self;
+	fn add(mut self, rhs: Self) -> Self::Output {
+		self += rhs;
+		self
+	}
+}
+
+impl<const R: usize, const C: usize, T: Scalar> ops::AddAssign for Matrix<R, C, T> {
+	fn add_assign(&mut self, rhs: Self) {
+		for r in 0..R {
+			self[r] += rhs[r];
+		}
+	}
+}
+
+impl<const R: usize, const C: usize, T: Scalar> ops::Sub for Matrix<R, C, T> {
+	type Output = Self;
+	fn sub(mut self, rhs: Self) -> Self::Output {
+		self -= rhs;
+		self
+	}
+}
+
+impl<const R: usize, const C: usize, T: Scalar> ops::SubAssign for Matrix<R, C, T> {
+	fn sub_assign(&mut self, rhs: Self) {
+		for r in 0..R {
+			self[r] -= rhs[r];
+		}
+	}
+}
+
+impl<const R: usize, const C: usize, T: Scalar> ops::Mul<T> for Matrix<R, C, T> {
+	type Output = Self;
+	fn mul(self, s: T) -> Self::Output {
+		Self(self.0.map(|row| row * s))
+	}
+}
+
+impl<const R: usize, const K: usize, const C: usize, T: Scalar> ops::Mul<Matrix<K, C, T>> for Matrix<R, K, T> {
+	type Output = Matrix<R, C, T>;
+	fn mul(self, rhs: Matrix<K, C, T>) -> Self::Output {
+		let mut m = <Self::Output as MatrixOps>::zero();
+		for r in 0..R {
+			for c in 0..C {
+				m[r][c] = (0..K).map(|i| self[r][i] * rhs[i][c]).sum();
+			}
+		}
+		m
+	}
+}
+
+impl<const R: usize, const C: usize, T: Scalar> ops::Mul<Vector<C, T>> for Matrix<R, C, T> {
+	type Output = Vector<R, T>;
+	fn mul(self, v: Vector<C, T>) -> Self::Output {
+		Vector(self.0.map(|row| row.dot(v)))
+	}
+}
+
+impl<const R: usize, const C: usize, T: Scalar> ops::Mul<Matrix<R, C, T>> for Vector<R, T> {
+	type Output = Vector<C, T>;
+	fn mul(self, m: Matrix<R, C, T>) -> Self::Output {
+		Vector(std::array::from_fn(|c| (0..R).map(|i| self[i] * m[i][c]).sum()))
+	}
 }
@@ -0,0 +1,520 @@
+use crate::{DVector, Real, Vector2, Vector3, Vector4};
+
+use super::{DMatrix, Matrix2x2, Matrix3x3, Matrix4x4, MatrixOps, SolveError};
+
+pub(crate) fn tolerance<T: Real>(m: &[T], n: usize) -> T {
+	let scale = m.iter().fold(T::ZERO, |max, v| max.max(v.abs()));
+	T::EPSILON * scale * T::from_f64(n as f64)
+}
+
+// Pivots are judged relative to the largest coefficient of their row, as if
+// every row were scaled to a largest entry of 1, so singularity doesn't
+// depend on the scale of individual equations. LU, QR, `reduce` and
+// `inverse` all share this criterion.
+
+// The largest of the first `width` entries of each row of the row-major `m`.
+pub(crate) fn row_scales<T: Real>(m: &[T], rows: usize, cols: usize, width: usize) -> Vec<T> {
+	(0..rows).map(|r| m[r * cols..r * cols + width].iter().fold(T::ZERO, |max, v| max.max(v.abs()))).collect()
+}
+
+// The row from `from` onwards whose entry in column `col` is largest relative to
+// its row's scale.
+pub(crate) fn pivot_row<T: Real>(m: &[T], cols: usize, col: usize, from: usize, scales: &[T]) -> usize {
+	(from..scales.len()).fold(from, |best, r| {
+		if relative(m[r * cols + col], scales[r]) > relative(m[best * cols + col], scales[best]) { r } else { best }
+	})
+}
+
+// Whether `pivot`, from a row with the given scale, is too small to divide by
+// in a system of `n` unknowns.
+pub(crate) fn negligible<T: Real>(pivot: T, scale: T, n: usize) -> bool {
+	relative(pivot, scale) <= T::EPSILON * T::from_f64(n as f64)
+}
+
+fn relative<T: Real>(v: T, scale: T) -> T {
+	if scale == T::ZERO { T::ZERO } else { v.abs() / scale }
+}
+
+// Whether the row-major `n`x`n` matrix `m` is singular.
+pub(crate) fn singular<T: Real>(m: &[T], n: usize) -> bool {
+	let mut m = m.to_vec();
+	lu(&mut m, n, &mut vec![0; n]).1
+}
+
+// Factors the row-major `n`x`n` matrix `m` in place into a unit lower
+// triangular L (below the diagonal) and an upper triangular U, with the rows
+// permuted according to `perm`. Returns whether an odd number of rows were
+// swapped, and whether the matrix is singular.
+pub(crate) fn lu<T: Real>(m: &mut [T], n: usize, perm: &mut [usize]) -> (bool, bool) {
+	let mut scales = row_scales(m, n, n, n);
+	let mut odd = false;
+	let mut singular = false;
+	for (i, p) in perm.iter_mut().enumerate() {
+		*p = i;
+	}
+	for k in 0..n {
+		let pivot = pivot_row(m, n, k, k, &scales);
+		if pivot != k {
+			for c in 0..n {
+				m.swap(pivot * n + c, k * n + c);
+			}
+			perm.swap(pivot, k);
+			scales.swap(pivot, k);
+			odd = !odd;
+		}
+		if negligible(m[k * n + k], scales[k], n) {
+			singular = true;
+			continue;
+		}
+		for r in k + 1..n {
+			let factor = m[r * n + k] / m[k * n + k];
+			m[r * n + k] = factor;
+			for c in k + 1..n {
+				let v = factor * m[k * n + c];
+				m[r * n + c] -= v;
+			}
+		}
+	}
+	(odd, singular)
+}
+
+// Solves `L * U * x = b` in place, with L and U packed as returned by `lu`.
+pub(crate) fn lu_solve<T: Real>(lu: &[T], n: usize, b: &mut [T]) {
+	for i in 0..n {
+		for j in 0..i {
+			let v = lu[i * n + j] * b[j];
+			b[i] -= v;
+		}
+	}
+	back_substitute(lu, n, b);
+}
+
+// Factors `m` in place into R, accumulating the orthogonal Q in `q`, using
+// Householder reflections. Returns whether an odd number of reflections were
+// applied, i.e. whether `det(Q) = -1`.
+pub(crate) fn qr<T: Real>(m: &mut [T], q: &mut [T], n: usize) -> bool {
+	let two = T::ONE + T::ONE;
+	let mut odd = false;
+	for r in 0..n {
+		for c in 0..n {
+			q[r * n + c] = if r == c { T::ONE } else { T::ZERO };
+		}
+	}
+	for k in 0..n.saturating_sub(1) {
+		let norm = (k..n).map(|i| m[i * n + k] * m[i * n + k]).sum::<T>().sqrt();
+		if norm == T::ZERO {
+			continue;
+		}
+		// The reflection vector v is stored in column k while it is applied.
+		let alpha = if m[k * n + k] > T::ZERO { -norm } else { norm };
+		m[k * n + k] -= alpha;
+		let v_sq: T = (k..n).map(|i| m[i * n + k] * m[i * n + k]).sum();
+		if v_sq == T::ZERO {
+			m[k * n + k] += alpha;
+			continue;
+		}
+		for c in k + 1..n {
+			let f = two * (k..n).map(|i| m[i * n + k] * m[i * n + c]).sum::<T>() / v_sq;
+			for i in k..n {
+				let v = f * m[i * n + k];
+				m[i * n + c] -= v;
+			}
+		}
+		for r in 0..n {
+			let f = two * (k..n).map(|i| q[r * n + i] * m[i * n + k]).sum::<T>() / v_sq;
+			for i in k..n {
+				let v = f * m[i * n + k];
+				q[r * n + i] -= v;
+			}
+		}
+		m[k * n + k] = alpha;
+		for i in k + 1..n {
+			m[i * n + k] = T::ZERO;
+		}
+		odd = !odd;
+	}
+	odd
+}
+
+// Solves `Q * R * x = b` in place.
+pub(crate) fn qr_solve<T: Real>(q: &[T], r: &[T], n: usize, b: &mut [T]) {
+	for i in 0..n {
+		b[n + i] = (0..n).map(|j| q[j * n + i] * b[j]).sum();
+	}
+	let (b, y) = b.split_at_mut(n);
+	b.copy_from_slice(y);
+	back_substitute(r, n, b);
+}
+
+// Factors the symmetric positive-definite `m` in place into the lower
+// triangular L with `L * Lᵀ = m`. Returns false if `m` is not symmetric
+// positive-definite.
+pub(crate) fn cholesky<T: Real>(m: &mut [T], n: usize) -> bool {
+	let tolerance = tolerance(m, n);
+	for r in 0..n {
+		for c in 0..r {
+			if (m[r * n + c] - m[c * n + r]).abs() > tolerance {
+				return false;
+			}
+		}
+	}
+	for j in 0..n {
+		let d = m[j * n + j] - (0..j).map(|k| m[j * n + k] * m[j * n + k]).sum::<T>();
+		if d <= tolerance {
+			return false;
+		}
+		let d = d.sqrt();
+		m[j * n + j] = d;
+		for i in j + 1..n {
+			let s = m[i * n + j] - (0..j).map(|k| m[i * n + k] * m[j * n + k]).sum::<T>();
+			m[i * n + j] = s / d;
+			m[j * n + i] = T::ZERO;
+		}
+	}
+	true
+}
+
+// Solves `L * Lᵀ * x = b` in place.
+pub(crate) fn cholesky_solve<T: Real>(l: &[T], n: usize, b: &mut [T]) {
+	for i in 0..n {
+		let s = b[i] - (0..i).map(|j| l[i * n + j] * b[j]).sum::<T>();
+		b[i] = s / l[i * n + i];
+	}
+	for i in (0..n).rev() {
+		let s = b[i] - (i + 1..n).map(|j| l[j * n + i] * b[j]).sum::<T>();
+		b[i] = s / l[i * n + i];
+	}
+}
+
+fn back_substitute<T: Real>(u: &[T], n: usize, b: &mut [T]) {
+	for i in (0..n).rev() {
+		let s = b[i] - (i + 1..n).map(|j| u[i * n + j] * b[j]).sum::<T>();
+		b[i] = s / u[i * n + i];
+	}
+}
+
+// `P * A = L * U`, where P is a permutation matrix.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Lu<M> {
+	lu: M,
+	p: M,
+	odd: bool,
+	singular: bool,
+}
+
+// `A = Q * R`, where Q is orthogonal and R upper triangular.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Qr<M> {
+	q: M,
+	r: M,
+	odd: bool,
+	singular: bool,
+}
+
+// `A = L * Lᵀ`, where L is lower triangular.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Cholesky<M> {
+	l: M,
+}
+
+macro_rules! decompositions {
+	($mat:ident, $vec:ident, $n:expr) => {
+		impl<T: Real> $mat<T> {
+			pub fn lu(&self) -> Lu<Self> {
+				let mut m = self.to_array();
+				let mut perm = [0; $n];
+				let (odd, singular) = lu(m.as_flattened_mut(), $n, &mut perm);
+				let mut p = <Self as MatrixOps>::zero();
+				for (r, &c) in perm.iter().enumerate() {
+					p[r][c] = T::ONE;
+				}
+				Lu { lu: m.into(), p, odd, singular }
+			}
+			pub fn qr(&self) -> Qr<Self> {
+				let mut r = self.to_array();
+				let mut q = [[T::ZERO; $n]; $n];
+				let odd = qr(r.as_flattened_mut(), q.as_flattened_mut(), $n);
+				Qr { q: q.into(), r: r.into(), odd, singular: singular(self.as_slice(), $n) }
+			}
+			pub fn cholesky(&self) -> Option<Cholesky<Self>> {
+				let mut l = self.to_array();
+				if cholesky(l.as_flattened_mut(), $n) {
+					Some(Cholesky { l: l.into() })
+				} else {
+					None
+				}
+			}
+			fn to_array(self) -> [[T; $n]; $n] {
+				let mut m = [[T::ZERO; $n]; $n];
+				for r in 0..$n {
+					for c in 0..$n {
+						m[r][c] = self[r][c];
+					}
+				}
+				m
+			}
+		}
+
+		impl<T: Real> Lu<$mat<T>> {
+			pub fn l(&self) -> $mat<T> {
+				let mut l = <$mat<T> as MatrixOps>::identity();
+				for r in 0..$n {
+					for c in 0..r {
+						l[r][c] = self.lu[r][c];
+					}
+				}
+				l
+			}
+			pub fn u(&self) -> $mat<T> {
+				let mut u = <$mat<T> as MatrixOps>::zero();
+				for r in 0..$n {
+					for c in r..$n {
+						u[r][c] = self.lu[r][c];
+					}
+				}
+				u
+			}
+			pub fn p(&self) -> $mat<T> {
+				self.p
+			}
+			pub fn det(&self) -> T {
+				let det = (0..$n).fold(T::ONE, |det, i| det * self.lu[i][i]);
+				if self.odd { -det } else { det }
+			}
+			pub fn solve(&self, b: $vec<T>) -> Result<$vec<T>, SolveError> {
+				if self.singular {
+					return Err(SolveError::Singular);
+				}
+				let mut x = [T::ZERO; $n];
+				let pb = self.p * b;
+				for i in 0..$n {
+					x[i] = pb[i];
+				}
+				lu_solve(self.lu.to_array().as_flattened(), $n, &mut x);
+				Ok(x.into())
+			}
+			pub fn inverse(&self) -> Option<$mat<T>> {
+				let mut inv = <$mat<T> as MatrixOps>::zero();
+				for c in 0..$n {
+					let mut e = $vec::splat(T::ZERO);
+					e[c] = T::ONE;
+					let col = self.solve(e).ok()?;
+					for r in 0..$n {
+						inv[r][c] = col[r];
+					}
+				}
+				Some(inv)
+			}
+		}
+
+		impl<T: Real> Qr<$mat<T>> {
+			pub fn q(&self) -> $mat<T> {
+				self.q
+			}
+			pub fn r(&self) -> $mat<T> {
+				self.r
+			}
+			pub fn det(&self) -> T {
+				let det = (0..$n).fold(T::ONE, |det, i| det * self.r[i][i]);
+				if self.odd { -det } else { det }
+			}
+			pub fn solve(&self, b: $vec<T>) -> Result<$vec<T>, SolveError> {
+				if self.singular {
+					return Err(SolveError::Singular);
+				}
+				let mut x = [T::ZERO; 2 * $n];
+				for i in 0..$n {
+					x[i] = b[i];
+				}
+				qr_solve(self.q.to_array().as_flattened(), self.r.to_array().as_flattened(), $n, &mut x);
+				let mut solution = [T::ZERO; $n];
+				solution.copy_from_slice(&x[..$n]);
+				Ok(solution.into())
+			}
+			pub fn inverse(&self) -> Option<$mat<T>> {
+				let mut inv = <$mat<T> as MatrixOps>::zero();
+				for c in 0..$n {
+					let mut e = $vec::splat(T::ZERO);
+					e[c] = T::ONE;
+					let col = self.solve(e).ok()?;
+					for r in 0..$n {
+						inv[r][c] = col[r];
+					}
+				}
+				Some(inv)
+			}
+		}
+
+		impl<T: Real> Cholesky<$mat<T>> {
+			pub fn l(&self) -> $mat<T> {
+				self.l
+			}
+			pub fn det(&self) -> T {
+				(0..$n).fold(T::ONE, |det, i| det * self.l[i][i] * self.l[i][i])
+			}
+			pub fn solve(&self, b: $vec<T>) -> $vec<T> {
+				let mut x = [T::ZERO; $n];
+				for i in 0..$n {
+					x[i] = b[i];
+				}
+				cholesky_solve(self.l.to_array().as_flattened(), $n, &mut x);
+				x.into()
+			}
+			pub fn inverse(&self) -> $mat<T> {
+				let mut inv = <$mat<T> as MatrixOps>::zero();
+				for c in 0..$n {
+					let mut e = $vec::splat(T::ZERO);
+					e[c] = T::ONE;
+					let col = self.solve(e);
+					for r in 0..$n {
+						inv[r][c] = col[r];
+					}
+				}
+				inv
+			}
+		}
+	};
+}
+
+decompositions!(Matrix2x2, Vector2, 2);
+decompositions!(Matrix3x3, Vector3, 3);
+decompositions!(Matrix4x4, Vector4, 4);
+
+impl<T: Real> DMatrix<T> {
+	pub fn lu(&self) -> Option<Lu<Self>> {
+		if !self.is_square() {
+			return None;
+		}
+		let n = self.height();
+		let mut m = self.as_slice().to_vec();
+		let mut perm = vec![0; n];
+		let (odd, singular) = lu(&mut m, n, &mut perm);
+		let mut p = Self::zero(n, n);
+		for (r, &c) in perm.iter().enumerate() {
+			p[r][c] = T::ONE;
+		}
+		Some(Lu { lu: Self::from_row_major(n, n, m), p, odd, singular })
+	}
+	pub fn qr(&self) -> Option<Qr<Self>> {
+		if !self.is_square() {
+			return None;
+		}
+		let n = self.height();
+		let mut r = self.as_slice().to_vec();
+		let mut q = vec![T::ZERO; n * n];
+		let odd = qr(&mut r, &mut q, n);
+		Some(Qr { q: Self::from_row_major(n, n, q), r: Self::from_row_major(n, n, r), odd, singular: singular(self.as_slice(), n) })
+	}
+	pub fn cholesky(&self) -> Option<Cholesky<Self>> {
+		if !self.is_square() {
+			return None;
+		}
+		let n = self.height();
+		let mut l = self.as_slice().to_vec();
+		if cholesky(&mut l, n) {
+			Some(Cholesky { l: Self::from_row_major(n, n, l) })
+		} else {
+			None
+		}
+	}
+}
+
+fn columns<T: Real>(n: usize, mut solve: impl FnMut(DVector<T>) -> Option<DVector<T>>) -> Option<DMatrix<T>> {
+	let mut inv = DMatrix::zero(n, n);
+	for c in 0..n {
+		let mut e = DVector::zero(n);
+		e[c] = T::ONE;
+		let col = solve(e)?;
+		for r in 0..n {
+			inv[r][c] = col[r];
+		}
+	}
+	Some(inv)
+}
+
+impl<T: Real> Lu<DMatrix<T>> {
+	pub fn l(&self) -> DMatrix<T> {
+		let n = self.lu.height();
+		let mut l = DMatrix::identity(n);
+		for r in 0..n {
+			l[r][..r].copy_from_slice(&self.lu[r][..r]);
+		}
+		l
+	}
+	pub fn u(&self) -> DMatrix<T> {
+		let n = self.lu.height();
+		let mut u = DMatrix::zero(n, n);
+		for r in 0..n {
+			u[r][r..].copy_from_slice(&self.lu[r][r..]);
+		}
+		u
+	}
+	pub fn p(&self) -> DMatrix<T> {
+		self.p.clone()
+	}
+	pub fn det(&self) -> T {
+		let det = (0..self.lu.height()).fold(T::ONE, |det, i| det * self.lu[i][i]);
+		if self.odd { -det } else { det }
+	}
+	pub fn solve(&self, b: &DVector<T>) -> Result<DVector<T>, SolveError> {
+		if b.len() != self.lu.height() {
+			return Err(SolveError::DimensionMismatch);
+		}
+		if self.singular {
+			return Err(SolveError::Singular);
+		}
+		let mut x = &self.p * b;
+		lu_solve(self.lu.as_slice(), self.lu.height(), &mut x.0);
+		Ok(x)
+	}
+	pub fn inverse(&self) -> Option<DMatrix<T>> {
+		columns(self.lu.height(), |e| self.solve(&e).ok())
+	}
+}
+
+impl<T: Real> Qr<DMatrix<T>> {
+	pub fn q(&self) -> DMatrix<T> {
+		self.q.clone()
+	}
+	pub fn r(&self) -> DMatrix<T> {
+		self.r.clone()
+	}
+	pub fn det(&self) -> T {
+		let det = (0..self.r.height()).fold(T::ONE, |det, i| det * self.r[i][i]);
+		if self.odd { -det } else { det }
+	}
+	pub fn solve(&self, b: &DVector<T>) -> Result<DVector<T>, SolveError> {
+		if b.len() != self.r.height() {
+			return Err(SolveError::DimensionMismatch);
+		}
+		if self.singular {
+			return Err(SolveError::Singular);
+		}
+		let n = self.r.height();
+		let mut x = b.as_slice().to_vec();
+		x.resize(2 * n, T::ZERO);
+		qr_solve(self.q.as_slice(), self.r.as_slice(), n, &mut x);
+		x.truncate(n);
+		Ok(x.into())
+	}
+	pub fn inverse(&self) -> Option<DMatrix<T>> {
+		columns(self.r.height(), |e| self.solve(&e).ok())
+	}
+}
+
+impl<T: Real> Cholesky<DMatrix<T>> {
+	pub fn l(&self) -> DMatrix<T> {
+		self.l.clone()
+	}
+	pub fn det(&self) -> T {
+		(0..self.l.height()).fold(T::ONE, |det, i| det * self.l[i][i] * self.l[i][i])
+	}
+	pub fn solve(&self, b: &DVector<T>) -> DVector<T> {
+		let mut x = b.clone();
+		cholesky_solve(self.l.as_slice(), self.l.height(), &mut x.0);
+		x
+	}
+	pub fn inverse(&self) -> DMatrix<T> {
+		columns(self.l.height(), |e| Some(self.solve(&e))).unwrap()
+	}
+}
@@ -0,0 +1,229 @@
+use crate::{DVector, Float, Real, Scalar, Vector};
+
+use super::{format, Matrix, SolveError};
+
+use std::convert::TryFrom;
+use std::{fmt, ops};
+
+// A row-major matrix whose dimensions are only known at runtime.
+#[derive(Clone, PartialEq)]
+pub struct DMatrix<T = Float> {
+	rows: usize,
+	cols: usize,
+	data: Vec<T>,
+}
+
+impl<T: Scalar> DMatrix<T> {
+	pub fn zero(rows: usize, cols: usize) -> Self {
+		Self { rows, cols, data: vec![T::ZERO; rows * cols] }
+	}
+	pub fn identity(n: usize) -> Self {
+		let mut m = Self::zero(n, n);
+		for i in 0..n {
+			m[i][i] = T::ONE;
+		}
+		m
+	}
+	pub fn from_row_major(rows: usize, cols: usize, data: Vec<T>) -> Self {
+		assert_eq!(data.len(), rows * cols, "data does not match a {}x{} matrix", rows, cols);
+		Self { rows, cols, data }
+	}
+	pub fn from_col_major(rows: usize, cols: usize, data: Vec<T>) -> Self {
+		Self::from_row_major(cols, rows, data).transpose()
+	}
+	pub fn to_col_major(&self) -> Vec<T> {
+		self.transpose().data
+	}
+	pub fn width(&self) -> usize {
+		self.cols
+	}
+	pub fn height(&self) -> usize {
+		self.rows
+	}
+	pub fn is_square(&self) -> bool {
+		self.rows == self.cols
+	}
+	pub fn as_slice(&self) -> &[T] {
+		&self.data
+	}
+	pub fn row(&self, r: usize) -> DVector<T> {
+		self[r].into()
+	}
+	pub fn col(&self, c: usize) -> DVector<T> {
+		(0..self.rows).map(|r| self[r][c]).collect::<Vec<_>>().into()
+	}
+	pub fn transpose(&self) -> Self {
+		let mut t = Self::zero(self.cols, self.rows);
+		for r in 0..self.rows {
+			for c in 0..self.cols {
+				t[c][r] = self[r][c];
+			}
+		}
+		t
+	}
+	pub fn trace(&self) -> T {
+		(0..self.rows.min(self.cols)).map(|i| self[i][i]).sum()
+	}
+}
+
+impl<T: Real> DMatrix<T> {
+	pub fn det(&self) -> Option<T> {
+		self.lu().map(|lu| lu.det())
+	}
+	pub fn inverse(&self) -> Option<Self> {
+		self.lu()?.inverse()
+	}
+	pub fn solve(&self, b: &DVector<T>) -> Result<DVector<T>, SolveError> {
+		self.lu().ok_or(SolveError::NotSquare)?.solve(b)
+	}
+}
+
+impl<const R: usize, const C: usize, T: Scalar> From<Matrix<R, C, T>> for DMatrix<T> {
+	fn from(m: Matrix<R, C, T>) -> Self {
+		Self {
+			rows: R,
+			cols: C,
+			data: (0..R).flat_map(|r| m[r].0).collect(),
+		}
+	}
+}
+
+// Gives the matrix back unchanged if it is not `R`x`C`.
+impl<const R: usize, const C: usize, T: Scalar> TryFrom<DMatrix<T>> for Matrix<R, C, T> {
+	type Error = DMatrix<T>;
+	fn try_from(m: DMatrix<T>) -> Result<Self, Self::Error> {
+		if (m.rows, m.cols) != (R, C) {
+			return Err(m);
+		}
+		Ok(Matrix(std::array::from_fn(|r| Vector(std::array::from_fn(|c| m[r][c])))))
+	}
+}
+
+impl<T: Scalar> ops::Index<usize> for DMatrix<T> {
+	type Output = [T];
+	fn index(&self, r: usize) -> &Self::Output {
+		&self.data[r * self.cols..(r + 1) * self.cols]
+	}
+}
+
+impl<T: Scalar> ops::IndexMut<usize> for DMatrix<T> {
+	fn index_mut(&mut self, r: usize) -> &mut Self::Output {
+		&mut self.data[r * self.cols..(r + 1) * self.cols]
+	}
+}
+
+impl<T: Scalar> fmt::Debug for DMatrix<T> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		format::fmt_debug(f, self.rows, self.cols, &self.data)
+	}
+}
+
+impl<T: Scalar> fmt::Display for DMatrix<T> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		format::fmt_display(f, self.rows, self.cols, &self.data)
+	}
+}
+
+impl<T: Scalar> ops::Neg for DMatrix<T> {
+	type Output = Self;
+	fn neg(mut self) -> Self::Output {
+		for v in self.data.iter_mut() {
+			*v = -*v;
+		}
+		self
+	}
+}
+
+impl<T: Scalar> ops::Add for DMatrix<T> {
+	type Output = Self;
+	fn add(mut self, rhs: Self) -> Self::Output {
+		self += rhs;
+		self
+	}
+}
+
+impl<T: Scalar> ops::AddAssign for DMatrix<T> {
+	fn add_assign(&mut self, rhs: Self) {
+		assert_eq!((self.rows, self.cols), (rhs.rows, rhs.cols), "matrix dimensions differ");
+		for (a, b) in self.data.iter_mut().zip(rhs.data) {
+			*a += b;
+		}
+	}
+}
+
+impl<T: Scalar> ops::Sub for DMatrix<T> {
+	type Output = Self;
+	fn sub(mut self, rhs: Self) -> Self::Output {
+		self -= rhs;
+		self
+	}
+}
+
+impl<T: Scalar> ops::SubAssign for DMatrix<T> {
+	fn sub_assign(&mut self, rhs: Self) {
+		assert_eq!((self.rows, self.cols), (rhs.rows, rhs.cols), "matrix dimensions differ");
+		for (a, b) in self.data.iter_mut().zip(rhs.data) {
+			*a -= b;
+		}
+	}
+}
+
+impl<T: Scalar> ops::Mul<T> for DMatrix<T> {
+	type Output = Self;
+	fn mul(mut self, s: T) -> Self::Output {
+		for v in self.data.iter_mut() {
+			*v *= s;
+		}
+		self
+	}
+}
+
+impl<T: Scalar> ops::Mul for &DMatrix<T> {
+	type Output = DMatrix<T>;
+	fn mul(self, rhs: Self) -> Self::Output {
+		assert_eq!(self.cols, rhs.rows, "cannot multiply a {}x{} matrix by a {}x{} matrix", self.rows, self.cols, rhs.rows, rhs.cols);
+		let mut m = DMatrix::zero(self.rows, rhs.cols);
+		for r in 0..self.rows {
+			for c in 0..rhs.cols {
+				m[r][c] = (0..self.cols).map(|i| self[r][i] * rhs[i][c]).sum();
+			}
+		}
+		m
+	}
+}
+
+impl<T: Scalar> ops::Mul for DMatrix<T> {
+	type Output = Self;
+	fn mul(self, rhs: Self) -> Self::Output {
+		&self * &rhs
+	}
+}
+
+impl<T: Scalar> ops::Mul<&DVector<T>> for &DMatrix<T> {
+	type Output = DVector<T>;
+	fn mul(self, v: &DVector<T>) -> Self::Output {
+		assert_eq!(self.cols, v.len(), "cannot multiply a {}x{} matrix by a vector of length {}", self.rows, self.cols, v.len());
+		(0..self.rows)
+			.map(|r| (0..self.cols).map(|c| self[r][c] * v[c]).sum())
+			.collect::<Vec<_>>()
+			.into()
+	}
+}
+
+impl<T: Scalar> ops::Mul<DVector<T>> for DMatrix<T> {
+	type Output = DVector<T>;
+	fn mul(self, v: DVector<T>) -> Self::Output {
+		&self * &v
+	}
+}
+
+impl<T: Scalar> ops::Mul<DMatrix<T>> for DVector<T> {
+	type Output = DVector<T>;
+	fn mul(self, m: DMatrix<T>) -> Self::Output {
+		assert_eq!(self.len(), m.rows, "cannot multiply a vector of length {} by a {}x{} matrix", self.len(), m.rows, m.cols);
+		(0..m.cols)
+			.map(|c| (0..m.rows).map(|r| self[r] * m[r][c]).sum())
+			.collect::<Vec<_>>()
+			.into()
+	}
+}
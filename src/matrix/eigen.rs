@@ -0,0 +1,137 @@
+use crate::{Real, Vector2, Vector3, Vector4};
+
+use super::{decompose::tolerance, Matrix2x2, Matrix3x3, Matrix4x4};
+
+// Eigenvalues in ascending order, with `vectors[i]` the normalized
+// eigenvector belonging to `values[i]`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SymmetricEigen<V, const N: usize> {
+	pub values: V,
+	pub vectors: [V; N],
+}
+
+// Diagonalizes the symmetric row-major `n`x`n` matrix `a` in place using
+// cyclic Jacobi rotations, accumulating the rotations into the columns of `v`.
+pub(crate) fn jacobi<T: Real>(a: &mut [T], v: &mut [T], n: usize) {
+	const MAX_SWEEPS: usize = 50;
+
+	let two = T::ONE + T::ONE;
+	let norm = a.iter().map(|x| *x * *x).sum::<T>().sqrt();
+	for r in 0..n {
+		for c in 0..n {
+			v[r * n + c] = if r == c { T::ONE } else { T::ZERO };
+		}
+	}
+	for _ in 0..MAX_SWEEPS {
+		let off = (0..n).flat_map(|p| (p + 1..n).map(move |q| (p, q)))
+			.map(|(p, q)| a[p * n + q] * a[p * n + q])
+			.sum::<T>()
+			.sqrt();
+		if off <= T::EPSILON * norm {
+			break;
+		}
+		for p in 0..n {
+			for q in p + 1..n {
+				let apq = a[p * n + q];
+				if apq == T::ZERO {
+					continue;
+				}
+				let theta = (a[q * n + q] - a[p * n + p]) / (two * apq);
+				let t = T::ONE / (theta.abs() + (theta * theta + T::ONE).sqrt());
+				let t = if theta < T::ZERO { -t } else { t };
+				let c = T::ONE / (t * t + T::ONE).sqrt();
+				let s = t * c;
+				for k in 0..n {
+					let (akp, akq) = (a[k * n + p], a[k * n + q]);
+					a[k * n + p] = c * akp - s * akq;
+					a[k * n + q] = s * akp + c * akq;
+				}
+				for k in 0..n {
+					let (apk, aqk) = (a[p * n + k], a[q * n + k]);
+					a[p * n + k] = c * apk - s * aqk;
+					a[q * n + k] = s * apk + c * aqk;
+				}
+				for k in 0..n {
+					let (vkp, vkq) = (v[k * n + p], v[k * n + q]);
+					v[k * n + p] = c * vkp - s * vkq;
+					v[k * n + q] = s * vkp + c * vkq;
+				}
+			}
+		}
+	}
+}
+
+fn is_symmetric<T: Real>(m: &[T], n: usize) -> bool {
+	let tolerance = tolerance(m, n);
+	(0..n).all(|r| (0..r).all(|c| (m[r * n + c] - m[c * n + r]).abs() <= tolerance))
+}
+
+impl<T: Real> Matrix2x2<T> {
+	pub fn symmetric_eigen(&self) -> Option<SymmetricEigen<Vector2<T>, 2>> {
+		let m = [self[0][0], self[0][1], self[1][0], self[1][1]];
+		if !is_symmetric(&m, 2) {
+			return None;
+		}
+		let two = T::ONE + T::ONE;
+		let (a, b, d) = (self[0][0], self[0][1], self[1][1]);
+		let mean = (a + d) / two;
+		let radius = (((a - d) / two) * ((a - d) / two) + b * b).sqrt();
+		let (low, high) = (mean - radius, mean + radius);
+
+		// Of the two equivalent formulations, pick the one that avoids
+		// cancellation.
+		let v = if (low - a).abs() > (low - d).abs() {
+			Vector2::new(b, low - a)
+		} else {
+			Vector2::new(low - d, b)
+		};
+		let v = if v.mag_sq() == T::ZERO {
+			if a <= d { Vector2::new(T::ONE, T::ZERO) } else { Vector2::new(T::ZERO, T::ONE) }
+		} else {
+			v.normalized()
+		};
+		Some(SymmetricEigen {
+			values: Vector2::new(low, high),
+			vectors: [v, Vector2::new(-v[1], v[0])],
+		})
+	}
+}
+
+macro_rules! jacobi_eigen {
+	($mat:ident, $vec:ident, $n:expr) => {
+		impl<T: Real> $mat<T> {
+			pub fn symmetric_eigen(&self) -> Option<SymmetricEigen<$vec<T>, $n>> {
+				let mut a = [[T::ZERO; $n]; $n];
+				for r in 0..$n {
+					for c in 0..$n {
+						a[r][c] = self[r][c];
+					}
+				}
+				if !is_symmetric(a.as_flattened(), $n) {
+					return None;
+				}
+				let mut v = [[T::ZERO; $n]; $n];
+				jacobi(a.as_flattened_mut(), v.as_flattened_mut(), $n);
+
+				let mut order = [0; $n];
+				for (i, o) in order.iter_mut().enumerate() {
+					*o = i;
+				}
+				order.sort_by(|&i, &j| a[i][i].partial_cmp(&a[j][j]).unwrap_or(std::cmp::Ordering::Equal));
+
+				let mut values = $vec::splat(T::ZERO);
+				let mut vectors = [$vec::splat(T::ZERO); $n];
+				for (i, &o) in order.iter().enumerate() {
+					values[i] = a[o][o];
+					for r in 0..$n {
+						vectors[i][r] = v[r][o];
+					}
+				}
+				Some(SymmetricEigen { values, vectors })
+			}
+		}
+	};
+}
+
+jacobi_eigen!(Matrix3x3, Vector3, 3);
+jacobi_eigen!(Matrix4x4, Vector4, 4);
@@ -0,0 +1,174 @@
+use crate::Scalar;
+
+use super::{DMatrix, Matrix};
+
+use std::cmp::max;
+use std::fmt;
+
+// Row-major cells formatted with the formatter's sign and precision flags and
+// padded so that decimal points line up within each column. The formatter's
+// width is the minimum width of every column.
+struct Grid {
+	rows: usize,
+	cols: usize,
+	cells: Vec<String>,
+	widths: Vec<usize>,
+}
+
+impl Grid {
+	fn new<T: fmt::Display>(f: &fmt::Formatter, rows: usize, cols: usize, data: &[T]) -> Self {
+		let cells: Vec<String> = data
+			.iter()
+			.map(|v| match (f.sign_plus(), f.precision()) {
+				(false, None) => format!("{}", v),
+				(false, Some(p)) => format!("{:.*}", p, v),
+				(true, None) => format!("{:+}", v),
+				(true, Some(p)) => format!("{:+.*}", p, v),
+			})
+			.collect();
+		let point = |s: &str| s.find('.').unwrap_or(s.len());
+		let mut whole = vec![0; cols];
+		let mut fraction = vec![0; cols];
+		for (i, s) in cells.iter().enumerate() {
+			whole[i % cols] = max(whole[i % cols], point(s));
+			fraction[i % cols] = max(fraction[i % cols], s.len() - point(s));
+		}
+		let widths: Vec<usize> = (0..cols).map(|c| max(whole[c] + fraction[c], f.width().unwrap_or(0))).collect();
+		let cells = cells
+			.iter()
+			.enumerate()
+			.map(|(i, s)| {
+				let (c, p) = (i % cols, point(s));
+				let aligned = format!("{:>2$}{:<3$}", &s[..p], &s[p..], whole[c], fraction[c]);
+				format!("{:>1$}", aligned, widths[c])
+			})
+			.collect();
+		Self { rows, cols, cells, widths }
+	}
+	fn row(&self, r: usize) -> &[String] {
+		&self.cells[r * self.cols..(r + 1) * self.cols]
+	}
+	fn inner_width(&self, cols: std::ops::Range<usize>) -> usize {
+		self.widths[cols].iter().map(|w| w + 2).sum()
+	}
+}
+
+// The shapes `solve` treats as augmented systems: 2x3, 3x4 and 4x5.
+fn is_augmented(rows: usize, cols: usize) -> bool {
+	cols == rows + 1 && (2..=4).contains(&rows)
+}
+
+// Augmented systems get a rule before their last column.
+fn fmt_box(f: &mut fmt::Formatter, grid: &Grid) -> fmt::Result {
+	let split = if is_augmented(grid.rows, grid.cols) { grid.rows } else { grid.cols };
+	let left = grid.inner_width(0..split);
+	let right = grid.inner_width(split..grid.cols);
+	let (top, bottom) = if split == grid.cols {
+		(format!("┌╴{:1$}╶┐", "", left.saturating_sub(2)), format!("└╴{:1$}╶┘", "", left.saturating_sub(2)))
+	} else {
+		(
+			format!("┌╴{:2$}╷{:3$}╶┐", "", "", left.saturating_sub(1), right.saturating_sub(1)),
+			format!("└╴{:2$}╵{:3$}╶┘", "", "", left.saturating_sub(1), right.saturating_sub(1)),
+		)
+	};
+	writeln!(f, "{}", top)?;
+	for r in 0..grid.rows {
+		if r > 0 {
+			write!(f, "│{:1$}│", "", left)?;
+			if split < grid.cols {
+				write!(f, "{:1$}│", "", right)?;
+			}
+			writeln!(f)?;
+		}
+		write!(f, "│")?;
+		for (c, cell) in grid.row(r).iter().enumerate() {
+			if c == split {
+				write!(f, "│")?;
+			}
+			write!(f, " {} ", cell)?;
+		}
+		writeln!(f, "│")?;
+	}
+	writeln!(f, "{}", bottom)
+}
+
+pub(crate) fn fmt_debug<T: fmt::Display>(f: &mut fmt::Formatter, rows: usize, cols: usize, data: &[T]) -> fmt::Result {
+	if is_augmented(rows, cols) {
+		writeln!(f, "Matrix{}x{}", rows, cols)?;
+	}
+	fmt_box(f, &Grid::new(f, rows, cols, data))
+}
+
+// `{:#}` gives plain rows of aligned columns, without the box.
+pub(crate) fn fmt_display<T: fmt::Display>(f: &mut fmt::Formatter, rows: usize, cols: usize, data: &[T]) -> fmt::Result {
+	let grid = Grid::new(f, rows, cols, data);
+	if !f.alternate() {
+		return fmt_box(f, &grid);
+	}
+	for r in 0..rows {
+		if r > 0 {
+			writeln!(f)?;
+		}
+		write!(f, "{}", grid.row(r).join("  ").trim_end())?;
+	}
+	Ok(())
+}
+
+// Renders as a LaTeX `bmatrix` environment.
+pub struct Latex<'a, T> {
+	rows: usize,
+	cols: usize,
+	data: &'a [T],
+}
+
+impl<'a, T: Scalar> fmt::Display for Latex<'a, T> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		let grid = Grid::new(f, self.rows, self.cols, self.data);
+		writeln!(f, "\\begin{{bmatrix}}")?;
+		for r in 0..self.rows {
+			let end = if r + 1 < self.rows { " \\\\" } else { "" };
+			writeln!(f, "\t{}{}", grid.row(r).join(" & ").trim_end(), end)?;
+		}
+		write!(f, "\\end{{bmatrix}}")
+	}
+}
+
+// Renders as a Markdown table with an empty header row.
+pub struct Markdown<'a, T> {
+	rows: usize,
+	cols: usize,
+	data: &'a [T],
+}
+
+impl<'a, T: Scalar> fmt::Display for Markdown<'a, T> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		let grid = Grid::new(f, self.rows, self.cols, self.data);
+		// Delimiter cells need at least one dash besides the alignment colon.
+		let widths: Vec<usize> = grid.widths.iter().map(|&w| max(w, 2)).collect();
+		let line = |cell: &dyn Fn(usize) -> String| (0..self.cols).map(|c| format!(" {:>1$} |", cell(c), widths[c])).collect::<String>();
+		write!(f, "|{}", line(&|_| String::new()))?;
+		write!(f, "\n|{}", line(&|c| format!("{:->1$}:", "", widths[c] - 1)))?;
+		for r in 0..self.rows {
+			write!(f, "\n|{}", line(&|c| grid.row(r)[c].clone()))?;
+		}
+		Ok(())
+	}
+}
+
+impl<const R: usize, const C: usize, T: Scalar> Matrix<R, C, T> {
+	pub fn latex(&self) -> Latex<'_, T> {
+		Latex { rows: R, cols: C, data: self.as_slice() }
+	}
+	pub fn markdown(&self) -> Markdown<'_, T> {
+		Markdown { rows: R, cols: C, data: self.as_slice() }
+	}
+}
+
+impl<T: Scalar> DMatrix<T> {
+	pub fn latex(&self) -> Latex<'_, T> {
+		Latex { rows: self.height(), cols: self.width(), data: self.as_slice() }
+	}
+	pub fn markdown(&self) -> Markdown<'_, T> {
+		Markdown { rows: self.height(), cols: self.width(), data: self.as_slice() }
+	}
+}
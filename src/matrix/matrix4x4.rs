@@ -1,121 +1,148 @@
-use crate::{Float, Vector4};
+use crate::{Rad, Real, Scalar, Vector3};
 
-use std::ops;
+use super::Matrix4x4;
 
-#[derive(Clone, PartialEq)]
-pub struct Matrix4x4([Vector4; 4]);
+use std::ops;
 
-impl Matrix4x4 {
-	pub const fn zero() -> Self {
-		Self([Vector4::new(0.0, 0.0, 0.0, 0.0); 4])
-	}
-	pub const fn identity() -> Self {
-		Self([
-			Vector4::new(1.0, 0.0, 0.0, 0.0),
-			Vector4::new(0.0, 1.0, 0.0, 0.0),
-			Vector4::new(0.0, 0.0, 1.0, 0.0),
-			Vector4::new(0.0, 0.0, 0.0, 1.0),
+impl<T: Real> Matrix4x4<T> {
+	pub fn translation(v: Vector3<T>) -> Self {
+		let (o, l) = (T::ZERO, T::ONE);
+		Self::from([
+			[l, o, o, v[0]],
+			[o, l, o, v[1]],
+			[o, o, l, v[2]],
+			[o, o, o, l],
 		])
 	}
-	pub const fn width() -> usize {
-		Vector4::len()
+	pub fn scale(v: Vector3<T>) -> Self {
+		let (o, l) = (T::ZERO, T::ONE);
+		Self::from([
+			[v[0], o, o, o],
+			[o, v[1], o, o],
+			[o, o, v[2], o],
+			[o, o, o, l],
+		])
 	}
-	pub const fn height() -> usize {
-		4
+	pub fn rotation_x(angle: impl Into<Rad<T>>) -> Self {
+		let (o, l) = (T::ZERO, T::ONE);
+		let (s, c) = angle.into().sin_cos();
+		Self::from([
+			[l, o, o, o],
+			[o, c, -s, o],
+			[o, s, c, o],
+			[o, o, o, l],
+		])
 	}
-}
-
-impl From<[Vector4; 4]> for Matrix4x4 {
-	fn from(v: [Vector4; 4]) -> Self {
-		Self(v)
+	pub fn rotation_y(angle: impl Into<Rad<T>>) -> Self {
+		let (o, l) = (T::ZERO, T::ONE);
+		let (s, c) = angle.into().sin_cos();
+		Self::from([
+			[c, o, s, o],
+			[o, l, o, o],
+			[-s, o, c, o],
+			[o, o, o, l],
+		])
 	}
-}
-
-impl From<[[Float; 4]; 4]> for Matrix4x4 {
-	fn from(v: [[Float; 4]; 4]) -> Self {
-		Self([
-			Vector4::from(v[0]),
-			Vector4::from(v[1]),
-			Vector4::from(v[2]),
-			Vector4::from(v[3]),
+	pub fn rotation_z(angle: impl Into<Rad<T>>) -> Self {
+		let (o, l) = (T::ZERO, T::ONE);
+		let (s, c) = angle.into().sin_cos();
+		Self::from([
+			[c, -s, o, o],
+			[s, c, o, o],
+			[o, o, l, o],
+			[o, o, o, l],
 		])
 	}
-}
-
-impl From<[Float; 4*4]> for Matrix4x4 {
-	fn from(v: [Float; 4*4]) -> Self {
-		Self([
-			Vector4::new(v[0], v[1], v[2], v[3]),
-			Vector4::new(v[4], v[5], v[6], v[7]),
-			Vector4::new(v[8], v[9], v[10], v[11]),
-			Vector4::new(v[12], v[13], v[14], v[15]),
+	pub fn rotation_axis(axis: Vector3<T>, angle: impl Into<Rad<T>>) -> Self {
+		let (o, l) = (T::ZERO, T::ONE);
+		let (s, c) = angle.into().sin_cos();
+		let t = l - c;
+		let a = axis.normalized();
+		let (x, y, z) = (a[0], a[1], a[2]);
+		Self::from([
+			[t * x * x + c, t * x * y - s * z, t * x * z + s * y, o],
+			[t * x * y + s * z, t * y * y + c, t * y * z - s * x, o],
+			[t * x * z - s * y, t * y * z + s * x, t * z * z + c, o],
+			[o, o, o, l],
 		])
 	}
-}
-
-impl ops::Index<usize> for Matrix4x4 {
-	type Output = Vector4;
-	fn index(&self, i: usize) -> &Self::Output {
-		&self.0[i]
+	pub fn look_at_rh(eye: Vector3<T>, target: Vector3<T>, up: Vector3<T>) -> Self {
+		let f = (target - eye).normalized();
+		let s = f.cross(up).normalized();
+		let u = s.cross(f);
+		Self::look_at(s, u, -f, eye)
 	}
-}
-
-impl ops::IndexMut<usize> for Matrix4x4 {
-	fn index_mut(&mut self, i: usize) -> &mut Self::Output {
-		&mut self.0[i]
+	pub fn look_at_lh(eye: Vector3<T>, target: Vector3<T>, up: Vector3<T>) -> Self {
+		let f = (target - eye).normalized();
+		let s = up.cross(f).normalized();
+		let u = f.cross(s);
+		Self::look_at(s, u, f, eye)
 	}
-}
-
-impl ops::Neg for Matrix4x4 {
-	type Output = Self;
-	fn neg(self) -> Self::Output {
-		[
-			-self[0],
-			-self[1],
-			-self[2],
-			-self[3],
-		].into()
+	fn look_at(s: Vector3<T>, u: Vector3<T>, f: Vector3<T>, eye: Vector3<T>) -> Self {
+		let (o, l) = (T::ZERO, T::ONE);
+		Self::from([
+			[s[0], s[1], s[2], -s.dot(eye)],
+			[u[0], u[1], u[2], -u.dot(eye)],
+			[f[0], f[1], f[2], -f.dot(eye)],
+			[o, o, o, l],
+		])
 	}
-}
-
-impl ops::Add for Matrix4x4 {
-	type Output = Self;
-	fn add(self, rhs: Self) -> Self::Output {
-		[
-			self[0] + rhs[0],
-			self[1] + rhs[1],
-			self[2] + rhs[2],
-			self[3] + rhs[3],
-		].into()
+	// OpenGL convention: depth is mapped to [-1, 1].
+	pub fn perspective_rh_gl(fovy: impl Into<Rad<T>>, aspect: T, near: T, far: T) -> Self {
+		let two = T::ONE + T::ONE;
+		Self::perspective(fovy.into(), aspect, -(far + near) / (far - near), -two * far * near / (far - near), -T::ONE)
 	}
-}
-
-impl ops::AddAssign for Matrix4x4 {
-	fn add_assign(&mut self, rhs: Self) {
-		self[0] += rhs[0];
-		self[1] += rhs[1];
-		self[2] += rhs[2];
-		self[3] += rhs[3];
+	// Direct3D/Vulkan/Metal convention: depth is mapped to [0, 1].
+	pub fn perspective_rh_zo(fovy: impl Into<Rad<T>>, aspect: T, near: T, far: T) -> Self {
+		Self::perspective(fovy.into(), aspect, far / (near - far), -far * near / (far - near), -T::ONE)
 	}
-}
-
-impl ops::Sub for Matrix4x4 {
-	type Output = Self;
-	fn sub(self, rhs: Self) -> Self::Output {
-		[
-			self[0] - rhs[0],
-			self[1] - rhs[1],
-			self[2] - rhs[2],
-			self[3] - rhs[3],
-		].into()
+	pub fn perspective_lh_gl(fovy: impl Into<Rad<T>>, aspect: T, near: T, far: T) -> Self {
+		let two = T::ONE + T::ONE;
+		Self::perspective(fovy.into(), aspect, (far + near) / (far - near), -two * far * near / (far - near), T::ONE)
+	}
+	pub fn perspective_lh_zo(fovy: impl Into<Rad<T>>, aspect: T, near: T, far: T) -> Self {
+		Self::perspective(fovy.into(), aspect, far / (far - near), -far * near / (far - near), T::ONE)
+	}
+	fn perspective(fovy: Rad<T>, aspect: T, zz: T, zw: T, wz: T) -> Self {
+		let o = T::ZERO;
+		let f = T::ONE / (fovy / (T::ONE + T::ONE)).tan();
+		Self::from([
+			[f / aspect, o, o, o],
+			[o, f, o, o],
+			[o, o, zz, zw],
+			[o, o, wz, o],
+		])
+	}
+	pub fn orthographic_rh_gl(left: T, right: T, bottom: T, top: T, near: T, far: T) -> Self {
+		let two = T::ONE + T::ONE;
+		Self::orthographic(left, right, bottom, top, -two / (far - near), -(far + near) / (far - near))
+	}
+	pub fn orthographic_rh_zo(left: T, right: T, bottom: T, top: T, near: T, far: T) -> Self {
+		Self::orthographic(left, right, bottom, top, -T::ONE / (far - near), -near / (far - near))
+	}
+	pub fn orthographic_lh_gl(left: T, right: T, bottom: T, top: T, near: T, far: T) -> Self {
+		let two = T::ONE + T::ONE;
+		Self::orthographic(left, right, bottom, top, two / (far - near), -(far + near) / (far - near))
+	}
+	pub fn orthographic_lh_zo(left: T, right: T, bottom: T, top: T, near: T, far: T) -> Self {
+		Self::orthographic(left, right, bottom, top, T::ONE / (far - near), -near / (far - near))
+	}
+	fn orthographic(left: T, right: T, bottom: T, top: T, zz: T, zw: T) -> Self {
+		let (o, l) = (T::ZERO, T::ONE);
+		let two = l + l;
+		Self::from([
+			[two / (right - left), o, o, -(right + left) / (right - left)],
+			[o, two / (top - bottom), o, -(top + bottom) / (top - bottom)],
+			[o, o, zz, zw],
+			[o, o, o, l],
+		])
 	}
 }
 
-impl ops::SubAssign for Matrix4x4 {
-	fn sub_assign(&mut self, rhs: Self) {
-		self[0] -= rhs[0];
-		self[1] -= rhs[1];
-		self[2] -= rhs[2];
-		self[3] -= rhs[3];
+// Transforms a direction, ignoring any translation. See `Point3` for positions.
+impl<T: Scalar> ops::Mul<Vector3<T>> for Matrix4x4<T> {
+	type Output = Vector3<T>;
+	fn mul(self, v: Vector3<T>) -> Self::Output {
+		(self * v.extend(T::ZERO)).truncate()
 	}
 }
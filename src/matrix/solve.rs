@@ -0,0 +1,166 @@
+use crate::{Real, Vector2, Vector3, Vector4};
+
+use super::{decompose::{negligible, pivot_row, row_scales}, Matrix2x2, Matrix2x3, Matrix3x3, Matrix3x4, Matrix4x4, Matrix4x5, MatrixOps};
+
+use std::{error, fmt};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SolveError {
+	// The coefficient matrix of a square system has no inverse.
+	Singular,
+	// The equations contradict each other.
+	Inconsistent,
+	// The system is consistent but underdetermined.
+	InfinitelyMany,
+	// An iterative solver broke down or ran out of iterations.
+	NotConverged,
+	// A method that needs a square coefficient matrix was given another shape.
+	NotSquare,
+	// The right-hand side doesn't have one entry per equation.
+	DimensionMismatch,
+}
+
+impl fmt::Display for SolveError {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			SolveError::Singular => write!(f, "coefficient matrix is singular"),
+			SolveError::Inconsistent => write!(f, "system of equations is inconsistent"),
+			SolveError::InfinitelyMany => write!(f, "system of equations has infinitely many solutions"),
+			SolveError::NotConverged => write!(f, "iterative solver did not converge"),
+			SolveError::NotSquare => write!(f, "coefficient matrix is not square"),
+			SolveError::DimensionMismatch => write!(f, "right-hand side does not match the coefficient matrix"),
+		}
+	}
+}
+
+impl error::Error for SolveError {}
+
+// Every solution is `particular` plus a linear combination of `basis`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GeneralSolution<V> {
+	pub particular: V,
+	pub basis: Vec<V>,
+}
+
+// Brings the row-major augmented matrix `m` into reduced row echelon form
+// using scaled partial pivoting. Returns the rank, with the pivot column of
+// row `r` stored in `pivots[r]`.
+pub(crate) fn reduce<T: Real>(m: &mut [T], rows: usize, cols: usize, pivots: &mut [usize]) -> Result<usize, SolveError> {
+	let unknowns = cols - 1;
+	let n = rows.max(unknowns);
+	let mut scales = row_scales(m, rows, cols, unknowns);
+	let rhs_tolerance = T::EPSILON * T::from_f64(n as f64) * (0..rows).fold(T::ZERO, |max, r| max.max(m[r * cols + unknowns].abs()));
+
+	let mut rank = 0;
+	for col in 0..unknowns {
+		if rank == rows {
+			break;
+		}
+		let pivot = pivot_row(m, cols, col, rank, &scales);
+		if negligible(m[pivot * cols + col], scales[pivot], n) {
+			for r in rank..rows {
+				m[r * cols + col] = T::ZERO;
+			}
+			continue;
+		}
+		for c in 0..cols {
+			m.swap(pivot * cols + c, rank * cols + c);
+		}
+		scales.swap(pivot, rank);
+		let v = m[rank * cols + col];
+		for c in 0..cols {
+			m[rank * cols + c] /= v;
+		}
+		for r in (0..rows).filter(|&r| r != rank) {
+			let factor = m[r * cols + col];
+			for c in 0..cols {
+				let v = m[rank * cols + c] * factor;
+				m[r * cols + c] -= v;
+			}
+			m[r * cols + col] = T::ZERO;
+		}
+		pivots[rank] = col;
+		rank += 1;
+	}
+
+	if (rank..rows).any(|r| m[r * cols + unknowns].abs() > rhs_tolerance) {
+		return Err(SolveError::Inconsistent);
+	}
+	Ok(rank)
+}
+
+macro_rules! augmented_solver {
+	($mat:ident, $vec:ident, $rows:expr, $cols:expr) => {
+		impl<T: Real> $mat<T> {
+			pub fn solve(self) -> Result<$vec<T>, SolveError> {
+				let solution = self.solve_general()?;
+				if solution.basis.is_empty() {
+					Ok(solution.particular)
+				} else {
+					Err(SolveError::InfinitelyMany)
+				}
+			}
+			pub fn solve_general(self) -> Result<GeneralSolution<$vec<T>>, SolveError> {
+				let mut m = [[T::ZERO; $cols]; $rows];
+				for r in 0..$rows {
+					for c in 0..$cols {
+						m[r][c] = self[r][c];
+					}
+				}
+				let m = m.as_flattened_mut();
+				let mut pivots = [0; $rows];
+				let rank = reduce(m, $rows, $cols, &mut pivots)?;
+				let pivots = &pivots[..rank];
+
+				let mut particular = $vec::splat(T::ZERO);
+				for (r, &c) in pivots.iter().enumerate() {
+					particular[c] = m[r * $cols + $cols - 1];
+				}
+				let basis = (0..$cols - 1)
+					.filter(|free| !pivots.contains(free))
+					.map(|free| {
+						let mut v = $vec::splat(T::ZERO);
+						v[free] = T::ONE;
+						for (r, &c) in pivots.iter().enumerate() {
+							v[c] = -m[r * $cols + free];
+						}
+						v
+					})
+					.collect();
+				Ok(GeneralSolution { particular, basis })
+			}
+		}
+	};
+}
+
+macro_rules! square_solver {
+	($mat:ident, $aug:ident, $vec:ident, $n:expr) => {
+		impl<T: Real> $mat<T> {
+			// Solves `self * x = b`.
+			pub fn solve(&self, b: $vec<T>) -> Result<$vec<T>, SolveError> {
+				self.augment(b).solve().map_err(|_| SolveError::Singular)
+			}
+			pub fn solve_general(&self, b: $vec<T>) -> Result<GeneralSolution<$vec<T>>, SolveError> {
+				self.augment(b).solve_general()
+			}
+			fn augment(&self, b: $vec<T>) -> $aug<T> {
+				let mut m = <$aug<T> as MatrixOps>::zero();
+				for r in 0..$n {
+					for c in 0..$n {
+						m[r][c] = self[r][c];
+					}
+					m[r][$n] = b[r];
+				}
+				m
+			}
+		}
+	};
+}
+
+augmented_solver!(Matrix2x3, Vector2, 2, 3);
+augmented_solver!(Matrix3x4, Vector3, 3, 4);
+augmented_solver!(Matrix4x5, Vector4, 4, 5);
+
+square_solver!(Matrix2x2, Matrix2x3, Vector2, 2);
+square_solver!(Matrix3x3, Matrix3x4, Vector3, 3);
+square_solver!(Matrix4x4, Matrix4x5, Vector4, 4);
@@ -0,0 +1,246 @@
+use crate::{DVector, Float, Real, Scalar, Vector};
+
+use super::{DMatrix, Matrix, SolveError};
+
+use std::ops;
+
+// A sparse matrix under construction, as a list of `(row, col, value)`
+// triplets. Repeated positions are summed when converting to CSR.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CooMatrix<T = Float> {
+	rows: usize,
+	cols: usize,
+	entries: Vec<(usize, usize, T)>,
+}
+
+// A sparse matrix in compressed sparse row form.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CsrMatrix<T = Float> {
+	rows: usize,
+	cols: usize,
+	row_offsets: Vec<usize>,
+	col_indices: Vec<usize>,
+	values: Vec<T>,
+}
+
+// The result of an iterative solve, where `residual` is the final
+// `|b - A * x|` relative to `|b|`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IterativeSolution<T = Float> {
+	pub x: DVector<T>,
+	pub iterations: usize,
+	pub residual: T,
+}
+
+impl<T: Scalar> CooMatrix<T> {
+	pub fn new(rows: usize, cols: usize) -> Self {
+		Self { rows, cols, entries: Vec::new() }
+	}
+	pub fn width(&self) -> usize {
+		self.cols
+	}
+	pub fn height(&self) -> usize {
+		self.rows
+	}
+	pub fn nnz(&self) -> usize {
+		self.entries.len()
+	}
+	pub fn push(&mut self, r: usize, c: usize, value: T) {
+		assert!(r < self.rows && c < self.cols, "({}, {}) is outside a {}x{} matrix", r, c, self.rows, self.cols);
+		self.entries.push((r, c, value));
+	}
+	// Adds an `N`x`N` block coupling node `r` to node `c`, for systems with
+	// `N` degrees of freedom per node.
+	pub fn push_block<const N: usize>(&mut self, r: usize, c: usize, block: &Matrix<N, N, T>) {
+		for i in 0..N {
+			for j in 0..N {
+				if block[i][j] != T::ZERO {
+					self.push(r * N + i, c * N + j, block[i][j]);
+				}
+			}
+		}
+	}
+}
+
+impl<T: Scalar> From<CooMatrix<T>> for CsrMatrix<T> {
+	fn from(mut coo: CooMatrix<T>) -> Self {
+		coo.entries.sort_by_key(|&(r, c, _)| (r, c));
+		let mut row_offsets = vec![0; coo.rows + 1];
+		let mut col_indices = Vec::with_capacity(coo.entries.len());
+		let mut values: Vec<T> = Vec::with_capacity(coo.entries.len());
+		let mut last = None;
+		for (r, c, v) in coo.entries {
+			if last == Some((r, c)) {
+				*values.last_mut().unwrap() += v;
+				continue;
+			}
+			last = Some((r, c));
+			row_offsets[r + 1] += 1;
+			col_indices.push(c);
+			values.push(v);
+		}
+		for r in 0..coo.rows {
+			row_offsets[r + 1] += row_offsets[r];
+		}
+		Self { rows: coo.rows, cols: coo.cols, row_offsets, col_indices, values }
+	}
+}
+
+impl<T: Scalar> CsrMatrix<T> {
+	pub fn width(&self) -> usize {
+		self.cols
+	}
+	pub fn height(&self) -> usize {
+		self.rows
+	}
+	pub fn nnz(&self) -> usize {
+		self.values.len()
+	}
+	pub fn get(&self, r: usize, c: usize) -> T {
+		self.row(r).find(|&(col, _)| col == c).map_or(T::ZERO, |(_, v)| v)
+	}
+	// The stored `(col, value)` pairs of row `r`.
+	pub fn row(&self, r: usize) -> impl Iterator<Item = (usize, T)> + '_ {
+		let range = self.row_offsets[r]..self.row_offsets[r + 1];
+		self.col_indices[range.clone()].iter().copied().zip(self.values[range].iter().copied())
+	}
+	pub fn to_dense(&self) -> DMatrix<T> {
+		let mut m = DMatrix::zero(self.rows, self.cols);
+		for r in 0..self.rows {
+			for (c, v) in self.row(r) {
+				m[r][c] = v;
+			}
+		}
+		m
+	}
+	// Multiplies with a vector of per-node blocks.
+	pub fn mul_blocks<const N: usize>(&self, x: &[Vector<N, T>]) -> Vec<Vector<N, T>> {
+		(self * &DVector::from_blocks(x)).to_blocks()
+	}
+	fn mul_into(&self, x: &[T], y: &mut [T]) {
+		for (r, y) in y.iter_mut().enumerate() {
+			*y = self.row(r).map(|(c, v)| v * x[c]).sum();
+		}
+	}
+}
+
+fn dot<T: Scalar>(a: &[T], b: &[T]) -> T {
+	a.iter().zip(b).map(|(&a, &b)| a * b).sum()
+}
+
+// `y += a * x`
+fn axpy<T: Scalar>(a: T, x: &[T], y: &mut [T]) {
+	for (y, &x) in y.iter_mut().zip(x) {
+		*y += a * x;
+	}
+}
+
+impl<T: Real> CsrMatrix<T> {
+	// For symmetric positive-definite matrices. Stops once the residual
+	// relative to `|b|` drops to `tolerance`.
+	pub fn conjugate_gradient(&self, b: &DVector<T>, tolerance: T, max_iterations: usize) -> Result<IterativeSolution<T>, SolveError> {
+		let n = self.square_system(b)?;
+		let b_mag = b.mag();
+		let mut x = vec![T::ZERO; n];
+		let mut r = b.as_slice().to_vec();
+		let mut p = r.clone();
+		let mut ap = vec![T::ZERO; n];
+		let mut r_sq = dot(&r, &r);
+		for iterations in 0..=max_iterations {
+			let residual = if b_mag == T::ZERO { T::ZERO } else { r_sq.sqrt() / b_mag };
+			if residual <= tolerance {
+				return Ok(IterativeSolution { x: x.into(), iterations, residual });
+			}
+			if iterations == max_iterations {
+				break;
+			}
+			self.mul_into(&p, &mut ap);
+			let p_ap = dot(&p, &ap);
+			if p_ap <= T::ZERO {
+				break;
+			}
+			let alpha = r_sq / p_ap;
+			axpy(alpha, &p, &mut x);
+			axpy(-alpha, &ap, &mut r);
+			let next = dot(&r, &r);
+			let beta = next / r_sq;
+			r_sq = next;
+			for (p, &r) in p.iter_mut().zip(&r) {
+				*p = r + beta * *p;
+			}
+		}
+		Err(SolveError::NotConverged)
+	}
+	// For general square matrices. Stops once the residual relative to `|b|`
+	// drops to `tolerance`.
+	pub fn bicgstab(&self, b: &DVector<T>, tolerance: T, max_iterations: usize) -> Result<IterativeSolution<T>, SolveError> {
+		let n = self.square_system(b)?;
+		let b_mag = b.mag();
+		let relative = |v: &[T]| if b_mag == T::ZERO { T::ZERO } else { dot(v, v).sqrt() / b_mag };
+		let mut x = vec![T::ZERO; n];
+		let mut r = b.as_slice().to_vec();
+		let r0 = r.clone();
+		let (mut rho, mut alpha, mut omega) = (T::ONE, T::ONE, T::ONE);
+		let mut v = vec![T::ZERO; n];
+		let mut p = vec![T::ZERO; n];
+		let mut s = vec![T::ZERO; n];
+		let mut t = vec![T::ZERO; n];
+		for iterations in 0..=max_iterations {
+			let residual = relative(&r);
+			if residual <= tolerance {
+				return Ok(IterativeSolution { x: x.into(), iterations, residual });
+			}
+			if iterations == max_iterations {
+				break;
+			}
+			let next = dot(&r0, &r);
+			if next == T::ZERO || omega == T::ZERO {
+				break;
+			}
+			let beta = (next / rho) * (alpha / omega);
+			rho = next;
+			for i in 0..n {
+				p[i] = r[i] + beta * (p[i] - omega * v[i]);
+			}
+			self.mul_into(&p, &mut v);
+			let r0_v = dot(&r0, &v);
+			if r0_v == T::ZERO {
+				break;
+			}
+			alpha = rho / r0_v;
+			s.copy_from_slice(&r);
+			axpy(-alpha, &v, &mut s);
+			axpy(alpha, &p, &mut x);
+			let residual = relative(&s);
+			if residual <= tolerance {
+				return Ok(IterativeSolution { x: x.into(), iterations: iterations + 1, residual });
+			}
+			self.mul_into(&s, &mut t);
+			let t_sq = dot(&t, &t);
+			omega = if t_sq == T::ZERO { T::ZERO } else { dot(&t, &s) / t_sq };
+			axpy(omega, &s, &mut x);
+			r.copy_from_slice(&s);
+			axpy(-omega, &t, &mut r);
+		}
+		Err(SolveError::NotConverged)
+	}
+	fn square_system(&self, b: &DVector<T>) -> Result<usize, SolveError> {
+		if self.rows != self.cols {
+			return Err(SolveError::NotSquare);
+		}
+		if self.rows != b.len() {
+			return Err(SolveError::DimensionMismatch);
+		}
+		Ok(self.rows)
+	}
+}
+
+impl<T: Scalar> ops::Mul<&DVector<T>> for &CsrMatrix<T> {
+	type Output = DVector<T>;
+	fn mul(self, x: &DVector<T>) -> Self::Output {
+		assert_eq!(self.cols, x.len(), "cannot multiply a {}x{} matrix by a vector of length {}", self.rows, self.cols, x.len());
+		let mut y = vec![T::ZERO; self.rows];
+		self.mul_into(x.as_slice(), &mut y);
+		y.into()
+	}
+}
@@ -0,0 +1,178 @@
+use crate::{Real, Vector2, Vector3, Vector4};
+
+use super::{Matrix2x2, Matrix3x3, Matrix3x4, Matrix4x3, Matrix4x4, MatrixOps};
+
+// `A = u * diag(singular_values) * vᵀ`, with the singular values in
+// descending order. `u` and `v` have orthonormal columns.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Svd<U, S, V> {
+	pub u: U,
+	pub singular_values: S,
+	pub v: V,
+}
+
+// Computes the thin SVD of the row-major `rows`x`cols` matrix `a`, where
+// `rows >= cols`, using one-sided Jacobi rotations. On return `a` holds U,
+// `v` holds V and `s` the singular values in descending order.
+pub(crate) fn svd<T: Real>(a: &mut [T], v: &mut [T], s: &mut [T], rows: usize, cols: usize) {
+	const MAX_SWEEPS: usize = 50;
+
+	let two = T::ONE + T::ONE;
+	for r in 0..cols {
+		for c in 0..cols {
+			v[r * cols + c] = if r == c { T::ONE } else { T::ZERO };
+		}
+	}
+	for _ in 0..MAX_SWEEPS {
+		let mut rotated = false;
+		for p in 0..cols {
+			for q in p + 1..cols {
+				let alpha: T = (0..rows).map(|i| a[i * cols + p] * a[i * cols + p]).sum();
+				let beta: T = (0..rows).map(|i| a[i * cols + q] * a[i * cols + q]).sum();
+				let gamma: T = (0..rows).map(|i| a[i * cols + p] * a[i * cols + q]).sum();
+				if gamma.abs() <= T::EPSILON * (alpha * beta).sqrt() {
+					continue;
+				}
+				rotated = true;
+				let zeta = (beta - alpha) / (two * gamma);
+				let t = T::ONE / (zeta.abs() + (zeta * zeta + T::ONE).sqrt());
+				let t = if zeta < T::ZERO { -t } else { t };
+				let c = T::ONE / (t * t + T::ONE).sqrt();
+				let s = t * c;
+				for i in 0..rows {
+					let (aip, aiq) = (a[i * cols + p], a[i * cols + q]);
+					a[i * cols + p] = c * aip - s * aiq;
+					a[i * cols + q] = s * aip + c * aiq;
+				}
+				for i in 0..cols {
+					let (vip, viq) = (v[i * cols + p], v[i * cols + q]);
+					v[i * cols + p] = c * vip - s * viq;
+					v[i * cols + q] = s * vip + c * viq;
+				}
+			}
+		}
+		if !rotated {
+			break;
+		}
+	}
+
+	for (j, s) in s.iter_mut().enumerate() {
+		*s = (0..rows).map(|i| a[i * cols + j] * a[i * cols + j]).sum::<T>().sqrt();
+	}
+	for j in 0..cols {
+		let max = (j..cols).fold(j, |max, k| if s[k] > s[max] { k } else { max });
+		if max != j {
+			s.swap(j, max);
+			for i in 0..rows {
+				a.swap(i * cols + j, i * cols + max);
+			}
+			for i in 0..cols {
+				v.swap(i * cols + j, i * cols + max);
+			}
+		}
+	}
+
+	let tolerance = T::EPSILON * T::from_f64(rows as f64) * s.first().copied().unwrap_or(T::ZERO);
+	for j in 0..cols {
+		if s[j] > tolerance {
+			for i in 0..rows {
+				a[i * cols + j] /= s[j];
+			}
+			continue;
+		}
+		// Complete U with a unit vector orthogonal to the previous columns.
+		for e in 0..rows {
+			for i in 0..rows {
+				a[i * cols + j] = if i == e { T::ONE } else { T::ZERO };
+			}
+			for k in 0..j {
+				let d: T = (0..rows).map(|i| a[i * cols + k] * a[i * cols + j]).sum();
+				for i in 0..rows {
+					let v = d * a[i * cols + k];
+					a[i * cols + j] -= v;
+				}
+			}
+			let norm = (0..rows).map(|i| a[i * cols + j] * a[i * cols + j]).sum::<T>().sqrt();
+			if norm > T::EPSILON.sqrt() {
+				for i in 0..rows {
+					a[i * cols + j] /= norm;
+				}
+				break;
+			}
+		}
+	}
+}
+
+macro_rules! svd {
+	($mat:ident, $rows:expr, $cols:expr, $u:ident, $s:ident, $v:ident, $pinv:ident) => {
+		impl<T: Real> $mat<T> {
+			pub fn svd(&self) -> Svd<$u<T>, $s<T>, $v<T>> {
+				const K: usize = if $rows < $cols { $rows } else { $cols };
+				let tall = $rows >= $cols;
+				let (m, n) = if tall { ($rows, $cols) } else { ($cols, $rows) };
+				let mut a = [T::ZERO; $rows * $cols];
+				for i in 0..$rows {
+					for j in 0..$cols {
+						if tall {
+							a[i * n + j] = self[i][j];
+						} else {
+							a[j * n + i] = self[i][j];
+						}
+					}
+				}
+				let mut v = [T::ZERO; K * K];
+				let mut s = [T::ZERO; K];
+				svd(&mut a, &mut v, &mut s, m, n);
+
+				let (left, right) = if tall { (&a[..], &v[..]) } else { (&v[..], &a[..]) };
+				let mut u = <$u<T> as MatrixOps>::zero();
+				for i in 0..$rows {
+					for j in 0..K {
+						u[i][j] = left[i * K + j];
+					}
+				}
+				let mut v = <$v<T> as MatrixOps>::zero();
+				for i in 0..$cols {
+					for j in 0..K {
+						v[i][j] = right[i * K + j];
+					}
+				}
+				Svd { u, singular_values: s.into(), v }
+			}
+			pub fn singular_values(&self) -> $s<T> {
+				self.svd().singular_values
+			}
+			// The number of singular values greater than `tolerance`.
+			pub fn rank(&self, tolerance: T) -> usize {
+				let s = self.singular_values();
+				(0..s.as_slice().len()).filter(|&i| s[i] > tolerance).count()
+			}
+			pub fn condition_number(&self) -> T {
+				let s = self.singular_values();
+				s[0] / s[s.as_slice().len() - 1]
+			}
+			// Singular values that are negligible relative to the largest one are
+			// treated as zero.
+			pub fn pseudo_inverse(&self) -> $pinv<T> {
+				let svd = self.svd();
+				let s = svd.singular_values;
+				let tolerance = T::EPSILON * T::from_f64(if $rows > $cols { $rows } else { $cols } as f64) * s[0];
+				let mut pinv = <$pinv<T> as MatrixOps>::zero();
+				for i in 0..$cols {
+					for j in 0..$rows {
+						pinv[i][j] = (0..s.as_slice().len())
+							.filter(|&l| s[l] > tolerance)
+							.map(|l| svd.v[i][l] * svd.u[j][l] / s[l])
+							.sum();
+					}
+				}
+				pinv
+			}
+		}
+	};
+}
+
+svd!(Matrix2x2, 2, 2, Matrix2x2, Vector2, Matrix2x2, Matrix2x2);
+svd!(Matrix3x3, 3, 3, Matrix3x3, Vector3, Matrix3x3, Matrix3x3);
+svd!(Matrix3x4, 3, 4, Matrix3x3, Vector3, Matrix4x3, Matrix4x3);
+svd!(Matrix4x4, 4, 4, Matrix4x4, Vector4, Matrix4x4, Matrix4x4);
@@ -0,0 +1,131 @@
+use crate::{DMatrix, DVector, Matrix, Point, Scalar, Vector};
+
+use std::str::FromStr;
+use std::{error, fmt};
+
+// Vectors are written as a single row or column, e.g. `(1, 2, 3)`, `[1 2 3]`
+// or `[1; 2; 3]`. Matrix rows are separated by semicolons or newlines, e.g.
+// `[1 2; 3 4]`. Elements are separated by commas and/or whitespace, and the
+// surrounding brackets are optional. Rows and columns are counted from 0.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+	// A bracket is unmatched or appears inside the outermost pair.
+	Brackets,
+	// The element at `row`, `col` is not a valid number.
+	InvalidNumber { row: usize, col: usize, text: String },
+	// The input is neither a single row nor a single column.
+	NotAVector { row: usize, len: usize },
+	// A vector has the wrong number of elements.
+	Length { expected: usize, found: usize },
+	// A matrix has the wrong number of rows.
+	Rows { expected: usize, found: usize },
+	// Row `row` of a matrix has the wrong number of elements.
+	Columns { row: usize, expected: usize, found: usize },
+}
+
+impl fmt::Display for ParseError {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			ParseError::Brackets => write!(f, "unbalanced or misplaced brackets"),
+			ParseError::InvalidNumber { row, col, text } => write!(f, "invalid number {:?} at row {}, column {}", text, row, col),
+			ParseError::NotAVector { row, len } => write!(f, "row {} has {} elements, but a column vector needs 1", row, len),
+			ParseError::Length { expected, found } => write!(f, "expected {} elements, found {}", expected, found),
+			ParseError::Rows { expected, found } => write!(f, "expected {} rows, found {}", expected, found),
+			ParseError::Columns { row, expected, found } => write!(f, "row {} has {} columns, expected {}", row, found, expected),
+		}
+	}
+}
+
+impl error::Error for ParseError {}
+
+fn strip_brackets(s: &str) -> Result<&str, ParseError> {
+	let s = s.trim();
+	let inner = match (s.chars().next(), s.chars().last()) {
+		(Some('('), Some(')')) | (Some('['), Some(']')) if s.len() >= 2 => &s[1..s.len() - 1],
+		_ => s,
+	};
+	if inner.contains(['(', ')', '[', ']']) {
+		return Err(ParseError::Brackets);
+	}
+	Ok(inner)
+}
+
+fn parse_rows<T: FromStr>(s: &str) -> Result<Vec<Vec<T>>, ParseError> {
+	strip_brackets(s)?
+		.split([';', '\n'])
+		.filter(|row| !row.trim().is_empty())
+		.enumerate()
+		.map(|(r, row)| {
+			row.split(|c: char| c == ',' || c.is_whitespace())
+				.filter(|t| !t.is_empty())
+				.enumerate()
+				.map(|(c, t)| t.parse().map_err(|_| ParseError::InvalidNumber { row: r, col: c, text: t.to_string() }))
+				.collect()
+		})
+		.collect()
+}
+
+fn parse_vector<T: FromStr>(s: &str) -> Result<Vec<T>, ParseError> {
+	let mut rows = parse_rows(s)?;
+	if rows.len() == 1 {
+		return Ok(rows.remove(0));
+	}
+	if let Some((r, row)) = rows.iter().enumerate().find(|(_, row)| row.len() != 1) {
+		return Err(ParseError::NotAVector { row: r, len: row.len() });
+	}
+	Ok(rows.into_iter().flatten().collect())
+}
+
+fn parse_matrix<T: FromStr>(s: &str, rows: Option<usize>, cols: Option<usize>) -> Result<Vec<Vec<T>>, ParseError> {
+	let parsed = parse_rows::<T>(s)?;
+	if let Some(expected) = rows.filter(|&r| r != parsed.len()) {
+		return Err(ParseError::Rows { expected, found: parsed.len() });
+	}
+	let expected = cols.or_else(|| parsed.first().map(Vec::len)).unwrap_or(0);
+	if let Some((row, r)) = parsed.iter().enumerate().find(|(_, r)| r.len() != expected) {
+		return Err(ParseError::Columns { row, expected, found: r.len() });
+	}
+	Ok(parsed)
+}
+
+impl<const N: usize, T: Scalar + FromStr> FromStr for Vector<N, T> {
+	type Err = ParseError;
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let v = parse_vector(s)?;
+		if v.len() != N {
+			return Err(ParseError::Length { expected: N, found: v.len() });
+		}
+		Ok(Vector(std::array::from_fn(|i| v[i])))
+	}
+}
+
+impl<const N: usize, T: Scalar + FromStr> FromStr for Point<N, T> {
+	type Err = ParseError;
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		s.parse::<Vector<N, T>>().map(Point::from)
+	}
+}
+
+impl<T: Scalar + FromStr> FromStr for DVector<T> {
+	type Err = ParseError;
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		parse_vector(s).map(DVector)
+	}
+}
+
+impl<const R: usize, const C: usize, T: Scalar + FromStr> FromStr for Matrix<R, C, T> {
+	type Err = ParseError;
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let rows = parse_matrix::<T>(s, Some(R), Some(C))?;
+		Ok(Matrix(std::array::from_fn(|r| Vector(std::array::from_fn(|c| rows[r][c])))))
+	}
+}
+
+impl<T: Scalar + FromStr> FromStr for DMatrix<T> {
+	type Err = ParseError;
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let rows = parse_matrix::<T>(s, None, None)?;
+		let cols = rows.first().map_or(0, Vec::len);
+		Ok(DMatrix::from_row_major(rows.len(), cols, rows.concat()))
+	}
+}
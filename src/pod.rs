@@ -0,0 +1,53 @@
+use crate::{DMatrix, DVector, Deg, Matrix, Point, Quaternion, Rad, Scalar, Vector};
+
+use bytemuck::{Pod, Zeroable};
+
+// All of these are transparent wrappers around `T` or arrays of `T`.
+unsafe impl<const N: usize, T: Zeroable> Zeroable for Vector<N, T> {}
+unsafe impl<const N: usize, T: Pod> Pod for Vector<N, T> {}
+unsafe impl<const N: usize, T: Zeroable> Zeroable for Point<N, T> {}
+unsafe impl<const N: usize, T: Pod> Pod for Point<N, T> {}
+unsafe impl<const R: usize, const C: usize, T: Zeroable> Zeroable for Matrix<R, C, T> {}
+unsafe impl<const R: usize, const C: usize, T: Pod> Pod for Matrix<R, C, T> {}
+unsafe impl<T: Zeroable> Zeroable for Quaternion<T> {}
+unsafe impl<T: Pod> Pod for Quaternion<T> {}
+unsafe impl<T: Zeroable> Zeroable for Rad<T> {}
+unsafe impl<T: Pod> Pod for Rad<T> {}
+unsafe impl<T: Zeroable> Zeroable for Deg<T> {}
+unsafe impl<T: Pod> Pod for Deg<T> {}
+
+impl<const N: usize, T: Scalar + Pod> Vector<N, T> {
+	pub fn as_bytes(&self) -> &[u8] {
+		bytemuck::bytes_of(self)
+	}
+}
+
+impl<const N: usize, T: Scalar + Pod> Point<N, T> {
+	pub fn as_bytes(&self) -> &[u8] {
+		bytemuck::bytes_of(self)
+	}
+}
+
+impl<const R: usize, const C: usize, T: Scalar + Pod> Matrix<R, C, T> {
+	pub fn as_bytes(&self) -> &[u8] {
+		bytemuck::bytes_of(self)
+	}
+}
+
+impl<T: Scalar + Pod> Quaternion<T> {
+	pub fn as_bytes(&self) -> &[u8] {
+		bytemuck::bytes_of(self)
+	}
+}
+
+impl<T: Scalar + Pod> DVector<T> {
+	pub fn as_bytes(&self) -> &[u8] {
+		bytemuck::cast_slice(self.as_slice())
+	}
+}
+
+impl<T: Scalar + Pod> DMatrix<T> {
+	pub fn as_bytes(&self) -> &[u8] {
+		bytemuck::cast_slice(self.as_slice())
+	}
+}
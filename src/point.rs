@@ -0,0 +1,183 @@
+use crate::{Float, Matrix3x3, Matrix3x4, Matrix4x4, Real, Scalar, Vector};
+
+use std::ops;
+
+// A position, as opposed to the direction or offset a `Vector` stands for.
+// The difference of two points is a vector, and transforms translate points
+// but not vectors.
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[repr(transparent)]
+pub struct Point<const N: usize, T = Float>(pub(crate) [T; N]);
+
+pub type Point2<T = Float> = Point<2, T>;
+pub type Point3<T = Float> = Point<3, T>;
+
+impl<const N: usize, T: Scalar> Point<N, T> {
+	pub const fn origin() -> Self {
+		Self([T::ZERO; N])
+	}
+	pub fn as_slice(&self) -> &[T] {
+		&self.0
+	}
+	pub fn as_mut_slice(&mut self) -> &mut [T] {
+		&mut self.0
+	}
+	// The offset from the origin.
+	pub fn to_vector(self) -> Vector<N, T> {
+		Vector(self.0)
+	}
+	pub fn distance_sq(&self, other: Self) -> T {
+		(*self - other).mag_sq()
+	}
+	pub fn lerp(&self, other: Self, t: T) -> Self {
+		*self + (other - *self) * t
+	}
+}
+
+impl<const N: usize, T: Real> Point<N, T> {
+	pub fn distance(&self, other: Self) -> T {
+		(*self - other).mag()
+	}
+}
+
+impl<T: Scalar> Point2<T> {
+	pub const fn new(x: T, y: T) -> Self {
+		Self([x, y])
+	}
+	pub fn x(&self) -> T {
+		self[0]
+	}
+	pub fn y(&self) -> T {
+		self[1]
+	}
+	pub fn extend(&self, z: T) -> Point3<T> {
+		Point3::new(self[0], self[1], z)
+	}
+}
+
+impl<T: Scalar> Point3<T> {
+	pub const fn new(x: T, y: T, z: T) -> Self {
+		Self([x, y, z])
+	}
+	pub fn x(&self) -> T {
+		self[0]
+	}
+	pub fn y(&self) -> T {
+		self[1]
+	}
+	pub fn z(&self) -> T {
+		self[2]
+	}
+	pub fn truncate(&self) -> Point2<T> {
+		Point2::new(self[0], self[1])
+	}
+}
+
+impl<const N: usize, T: Scalar> From<[T; N]> for Point<N, T> {
+	fn from(p: [T; N]) -> Self {
+		Self(p)
+	}
+}
+
+impl<T: Scalar> From<(T, T)> for Point2<T> {
+	fn from(p: (T, T)) -> Self {
+		Self([p.0, p.1])
+	}
+}
+
+impl<T: Scalar> From<(T, T, T)> for Point3<T> {
+	fn from(p: (T, T, T)) -> Self {
+		Self([p.0, p.1, p.2])
+	}
+}
+
+// The point at offset `v` from the origin.
+impl<const N: usize, T: Scalar> From<Vector<N, T>> for Point<N, T> {
+	fn from(v: Vector<N, T>) -> Self {
+		Self(v.0)
+	}
+}
+
+impl<const N: usize, T: Scalar> From<Point<N, T>> for Vector<N, T> {
+	fn from(p: Point<N, T>) -> Self {
+		p.to_vector()
+	}
+}
+
+impl<const N: usize, T: Scalar> ops::Index<usize> for Point<N, T> {
+	type Output = T;
+	fn index(&self, i: usize) -> &Self::Output {
+		&self.0[i]
+	}
+}
+
+impl<const N: usize, T: Scalar> ops::IndexMut<usize> for Point<N, T> {
+	fn index_mut(&mut self, i: usize) -> &mut Self::Output {
+		&mut self.0[i]
+	}
+}
+
+impl<const N: usize, T: Scalar> ops::Sub for Point<N, T> {
+	type Output = Vector<N, T>;
+	fn sub(self, other: Self) -> Self::Output {
+		self.to_vector() - other.to_vector()
+	}
+}
+
+impl<const N: usize, T: Scalar> ops::Add<Vector<N, T>> for Point<N, T> {
+	type Output = Self;
+	fn add(mut self, v: Vector<N, T>) -> Self::Output {
+		self += v;
+		self
+	}
+}
+
+impl<const N: usize, T: Scalar> ops::Sub<Vector<N, T>> for Point<N, T> {
+	type Output = Self;
+	fn sub(mut self, v: Vector<N, T>) -> Self::Output {
+		self -= v;
+		self
+	}
+}
+
+impl<const N: usize, T: Scalar> ops::AddAssign<Vector<N, T>> for Point<N, T> {
+	fn add_assign(&mut self, v: Vector<N, T>) {
+		for i in 0..N {
+			self[i] += v[i];
+		}
+	}
+}
+
+impl<const N: usize, T: Scalar> ops::SubAssign<Vector<N, T>> for Point<N, T> {
+	fn sub_assign(&mut self, v: Vector<N, T>) {
+		for i in 0..N {
+			self[i] -= v[i];
+		}
+	}
+}
+
+// Points are transformed as homogeneous coordinates with `w = 1`.
+
+impl<T: Scalar> ops::Mul<Point3<T>> for Matrix3x4<T> {
+	type Output = Point3<T>;
+	fn mul(self, p: Point3<T>) -> Self::Output {
+		(self * p.to_vector().extend(T::ONE)).into()
+	}
+}
+
+// Divides by the resulting `w`, so projections work as well.
+impl<T: Real> ops::Mul<Point3<T>> for Matrix4x4<T> {
+	type Output = Point3<T>;
+	fn mul(self, p: Point3<T>) -> Self::Output {
+		let h = self * p.to_vector().extend(T::ONE);
+		(h.truncate() / h[3]).into()
+	}
+}
+
+impl<T: Real> ops::Mul<Point2<T>> for Matrix3x3<T> {
+	type Output = Point2<T>;
+	fn mul(self, p: Point2<T>) -> Self::Output {
+		let h = self * p.to_vector().extend(T::ONE);
+		(h.truncate() / h[2]).into()
+	}
+}
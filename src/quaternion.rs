@@ -0,0 +1,231 @@
+use crate::{Float, Matrix3x3, Matrix4x4, Rad, Real, Scalar, Vector3};
+
+use std::ops;
+
+// Laid out in memory as `[w, x, y, z]`.
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[repr(transparent)]
+pub struct Quaternion<T = Float>([T; 4]);
+
+impl<T: Scalar> Quaternion<T> {
+	pub const fn new(w: T, x: T, y: T, z: T) -> Self {
+		Self([w, x, y, z])
+	}
+	pub const fn identity() -> Self {
+		Self([T::ONE, T::ZERO, T::ZERO, T::ZERO])
+	}
+	pub fn from_parts(w: T, v: Vector3<T>) -> Self {
+		Self([w, v[0], v[1], v[2]])
+	}
+	pub fn w(&self) -> T {
+		self.0[0]
+	}
+	pub fn x(&self) -> T {
+		self.0[1]
+	}
+	pub fn y(&self) -> T {
+		self.0[2]
+	}
+	pub fn z(&self) -> T {
+		self.0[3]
+	}
+	pub fn vector(&self) -> Vector3<T> {
+		Vector3::new(self.x(), self.y(), self.z())
+	}
+	pub fn as_slice(&self) -> &[T] {
+		&self.0
+	}
+	pub fn dot(&self, other: Self) -> T {
+		self.0.iter().zip(other.0.iter()).map(|(a, b)| *a * *b).sum()
+	}
+	pub fn mag_sq(&self) -> T {
+		self.dot(*self)
+	}
+	pub fn conjugate(&self) -> Self {
+		Self([self.w(), -self.x(), -self.y(), -self.z()])
+	}
+}
+
+impl<T: Real> Quaternion<T> {
+	pub fn from_axis_angle(axis: Vector3<T>, angle: impl Into<Rad<T>>) -> Self {
+		let (s, c) = (angle.into() / (T::ONE + T::ONE)).sin_cos();
+		Self::from_parts(c, axis.normalized() * s)
+	}
+	// Rotates by `roll` around x, then `pitch` around y, then `yaw` around z.
+	pub fn from_euler(roll: impl Into<Rad<T>>, pitch: impl Into<Rad<T>>, yaw: impl Into<Rad<T>>) -> Self {
+		let two = T::ONE + T::ONE;
+		let (sr, cr) = (roll.into() / two).sin_cos();
+		let (sp, cp) = (pitch.into() / two).sin_cos();
+		let (sy, cy) = (yaw.into() / two).sin_cos();
+		Self([
+			cr * cp * cy + sr * sp * sy,
+			sr * cp * cy - cr * sp * sy,
+			cr * sp * cy + sr * cp * sy,
+			cr * cp * sy - sr * sp * cy,
+		])
+	}
+	pub fn mag(&self) -> T {
+		self.mag_sq().sqrt()
+	}
+	pub fn normalized(&self) -> Self {
+		*self / self.mag()
+	}
+	pub fn normalize(&mut self) {
+		*self = self.normalized();
+	}
+	pub fn inverse(&self) -> Self {
+		self.conjugate() / self.mag_sq()
+	}
+	pub fn rotate(&self, v: Vector3<T>) -> Vector3<T> {
+		(*self * Self::from_parts(T::ZERO, v) * self.inverse()).vector()
+	}
+	pub fn nlerp(self, other: Self, t: T) -> Self {
+		let other = if self.dot(other) < T::ZERO { -other } else { other };
+		(self * (T::ONE - t) + other * t).normalized()
+	}
+	pub fn slerp(self, other: Self, t: T) -> Self {
+		let mut cos = self.dot(other);
+		let other = if cos < T::ZERO {
+			cos = -cos;
+			-other
+		} else {
+			other
+		};
+		if cos >= T::ONE - T::EPSILON.sqrt() {
+			return self.nlerp(other, t);
+		}
+		let theta = cos.acos();
+		let sin = theta.sin();
+		self * (((T::ONE - t) * theta).sin() / sin) + other * ((t * theta).sin() / sin)
+	}
+}
+
+impl<T: Real> From<Quaternion<T>> for Matrix3x3<T> {
+	fn from(q: Quaternion<T>) -> Self {
+		let q = q.normalized();
+		let (w, x, y, z) = (q.w(), q.x(), q.y(), q.z());
+		let (l, two) = (T::ONE, T::ONE + T::ONE);
+		Self::from([
+			[l - two * (y * y + z * z), two * (x * y - w * z), two * (x * z + w * y)],
+			[two * (x * y + w * z), l - two * (x * x + z * z), two * (y * z - w * x)],
+			[two * (x * z - w * y), two * (y * z + w * x), l - two * (x * x + y * y)],
+		])
+	}
+}
+
+impl<T: Real> From<Quaternion<T>> for Matrix4x4<T> {
+	fn from(q: Quaternion<T>) -> Self {
+		let m = Matrix3x3::from(q);
+		let o = T::ZERO;
+		Self::from([
+			[m[0][0], m[0][1], m[0][2], o],
+			[m[1][0], m[1][1], m[1][2], o],
+			[m[2][0], m[2][1], m[2][2], o],
+			[o, o, o, T::ONE],
+		])
+	}
+}
+
+impl<T: Real> From<Matrix3x3<T>> for Quaternion<T> {
+	fn from(m: Matrix3x3<T>) -> Self {
+		let (l, two) = (T::ONE, T::ONE + T::ONE);
+		let four = two + two;
+		let trace = m[0][0] + m[1][1] + m[2][2];
+		let q = if trace > T::ZERO {
+			let s = (trace + l).sqrt() * two;
+			Self([s / four, (m[2][1] - m[1][2]) / s, (m[0][2] - m[2][0]) / s, (m[1][0] - m[0][1]) / s])
+		} else if m[0][0] > m[1][1] && m[0][0] > m[2][2] {
+			let s = (l + m[0][0] - m[1][1] - m[2][2]).sqrt() * two;
+			Self([(m[2][1] - m[1][2]) / s, s / four, (m[0][1] + m[1][0]) / s, (m[0][2] + m[2][0]) / s])
+		} else if m[1][1] > m[2][2] {
+			let s = (l + m[1][1] - m[0][0] - m[2][2]).sqrt() * two;
+			Self([(m[0][2] - m[2][0]) / s, (m[0][1] + m[1][0]) / s, s / four, (m[1][2] + m[2][1]) / s])
+		} else {
+			let s = (l + m[2][2] - m[0][0] - m[1][1]).sqrt() * two;
+			Self([(m[1][0] - m[0][1]) / s, (m[0][2] + m[2][0]) / s, (m[1][2] + m[2][1]) / s, s / four])
+		};
+		q.normalized()
+	}
+}
+
+impl<T: Real> From<Matrix4x4<T>> for Quaternion<T> {
+	fn from(m: Matrix4x4<T>) -> Self {
+		Matrix3x3::from([
+			[m[0][0], m[0][1], m[0][2]],
+			[m[1][0], m[1][1], m[1][2]],
+			[m[2][0], m[2][1], m[2][2]],
+		]).into()
+	}
+}
+
+impl<T: Scalar> ops::Neg for Quaternion<T> {
+	type Output = Self;
+	fn neg(self) -> Self::Output {
+		Self([-self.0[0], -self.0[1], -self.0[2], -self.0[3]])
+	}
+}
+
+impl<T: Scalar> ops::Add for Quaternion<T> {
+	type Output = Self;
+	fn add(self, rhs: Self) -> Self::Output {
+		Self([
+			self.0[0] + rhs.0[0],
+			self.0[1] + rhs.0[1],
+			self.0[2] + rhs.0[2],
+			self.0[3] + rhs.0[3],
+		])
+	}
+}
+
+impl<T: Scalar> ops::Sub for Quaternion<T> {
+	type Output = Self;
+	fn sub(self, rhs: Self) -> Self::Output {
+		Self([
+			self.0[0] - rhs.0[0],
+			self.0[1] - rhs.0[1],
+			self.0[2] - rhs.0[2],
+			self.0[3] - rhs.0[3],
+		])
+	}
+}
+
+impl<T: Scalar> ops::Mul<T> for Quaternion<T> {
+	type Output = Self;
+	fn mul(self, s: T) -> Self::Output {
+		Self([self.0[0] * s, self.0[1] * s, self.0[2] * s, self.0[3] * s])
+	}
+}
+
+impl<T: Scalar> ops::Div<T> for Quaternion<T> {
+	type Output = Self;
+	fn div(self, s: T) -> Self::Output {
+		Self([self.0[0] / s, self.0[1] / s, self.0[2] / s, self.0[3] / s])
+	}
+}
+
+impl<T: Scalar> ops::Mul for Quaternion<T> {
+	type Output = Self;
+	fn mul(self, rhs: Self) -> Self::Output {
+		let (a1, b1, c1, d1) = (self.w(), self.x(), self.y(), self.z());
+		let (a2, b2, c2, d2) = (rhs.w(), rhs.x(), rhs.y(), rhs.z());
+		Self([
+			a1 * a2 - b1 * b2 - c1 * c2 - d1 * d2,
+			a1 * b2 + b1 * a2 + c1 * d2 - d1 * c2,
+			a1 * c2 - b1 * d2 + c1 * a2 + d1 * b2,
+			a1 * d2 + b1 * c2 - c1 * b2 + d1 * a2,
+		])
+	}
+}
+
+impl<T: Scalar> ops::MulAssign for Quaternion<T> {
+	fn mul_assign(&mut self, rhs: Self) {
+		*self = *self * rhs;
+	}
+}
+
+impl<T: Real> ops::Mul<Vector3<T>> for Quaternion<T> {
+	type Output = Vector3<T>;
+	fn mul(self, v: Vector3<T>) -> Self::Output {
+		self.rotate(v)
+	}
+}
@@ -0,0 +1,93 @@
+use std::{fmt, iter, ops};
+
+pub trait Scalar:
+	Copy + PartialEq + PartialOrd + fmt::Debug + fmt::Display + iter::Sum
+	+ ops::Add<Output = Self> + ops::Sub<Output = Self>
+	+ ops::Mul<Output = Self> + ops::Div<Output = Self>
+	+ ops::Neg<Output = Self>
+	+ ops::AddAssign + ops::SubAssign + ops::MulAssign + ops::DivAssign
+{
+	const ZERO: Self;
+	const ONE: Self;
+	fn abs(self) -> Self;
+	fn min(self, other: Self) -> Self;
+	fn max(self, other: Self) -> Self;
+}
+
+pub trait Real: Scalar {
+	const EPSILON: Self;
+	fn from_f64(v: f64) -> Self;
+	fn sqrt(self) -> Self;
+	fn sin(self) -> Self;
+	fn cos(self) -> Self;
+	fn tan(self) -> Self;
+	fn asin(self) -> Self;
+	fn acos(self) -> Self;
+	fn atan2(self, x: Self) -> Self;
+	fn rem_euclid(self, rhs: Self) -> Self;
+}
+
+macro_rules! for_all_scalar_types {
+	($t:ty, $zero:expr, $one:expr) => {
+		impl Scalar for $t {
+			const ZERO: Self = $zero;
+			const ONE: Self = $one;
+			fn abs(self) -> Self {
+				<$t>::abs(self)
+			}
+			fn min(self, other: Self) -> Self {
+				if other < self { other } else { self }
+			}
+			fn max(self, other: Self) -> Self {
+				if other > self { other } else { self }
+			}
+		}
+	};
+}
+
+macro_rules! for_all_real_types {
+	($t:ty) => {
+		for_all_scalar_types!($t, 0.0, 1.0);
+
+		impl Real for $t {
+			const EPSILON: Self = <$t>::EPSILON;
+			fn from_f64(v: f64) -> Self {
+				v as $t
+			}
+			fn sqrt(self) -> Self {
+				<$t>::sqrt(self)
+			}
+			fn sin(self) -> Self {
+				<$t>::sin(self)
+			}
+			fn cos(self) -> Self {
+				<$t>::cos(self)
+			}
+			fn tan(self) -> Self {
+				<$t>::tan(self)
+			}
+			fn asin(self) -> Self {
+				<$t>::asin(self)
+			}
+			fn acos(self) -> Self {
+				<$t>::acos(self)
+			}
+			fn atan2(self, x: Self) -> Self {
+				<$t>::atan2(self, x)
+			}
+			fn rem_euclid(self, rhs: Self) -> Self {
+				<$t>::rem_euclid(self, rhs)
+			}
+		}
+	};
+}
+
+for_all_real_types!(f32);
+for_all_real_types!(f64);
+
+for_all_scalar_types!(i8, 0, 1);
+for_all_scalar_types!(i16, 0, 1);
+for_all_scalar_types!(i32, 0, 1);
+for_all_scalar_types!(i64, 0, 1);
+for_all_scalar_types!(i128, 0, 1);
+for_all_scalar_types!(isize, 0, 1);
@@ -0,0 +1,144 @@
+use crate::{DMatrix, DVector, Deg, Matrix, Point, Quaternion, Rad, Scalar, Vector};
+
+use serde::de::{self, Deserialize, Deserializer, SeqAccess, Visitor};
+use serde::ser::{Serialize, SerializeSeq, SerializeTuple, Serializer};
+
+use std::convert::TryInto;
+use std::{fmt, marker::PhantomData};
+
+// Vectors, points and quaternions are written as fixed-length arrays,
+// matrices as arrays of rows and angles as plain numbers.
+
+fn serialize_array<S: Serializer, E: Serialize>(serializer: S, elements: &[E]) -> Result<S::Ok, S::Error> {
+	let mut tuple = serializer.serialize_tuple(elements.len())?;
+	for e in elements {
+		tuple.serialize_element(e)?;
+	}
+	tuple.end()
+}
+
+struct ArrayVisitor<E, const N: usize>(PhantomData<E>);
+
+impl<'de, E: Deserialize<'de>, const N: usize> Visitor<'de> for ArrayVisitor<E, N> {
+	type Value = [E; N];
+	fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "an array of length {}", N)
+	}
+	fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+		let mut elements = Vec::with_capacity(N);
+		while let Some(e) = seq.next_element()? {
+			if elements.len() == N {
+				return Err(de::Error::invalid_length(N + 1, &self));
+			}
+			elements.push(e);
+		}
+		let len = elements.len();
+		elements.try_into().map_err(|_| de::Error::invalid_length(len, &self))
+	}
+}
+
+fn deserialize_array<'de, D: Deserializer<'de>, E: Deserialize<'de>, const N: usize>(deserializer: D) -> Result<[E; N], D::Error> {
+	deserializer.deserialize_tuple(N, ArrayVisitor(PhantomData))
+}
+
+impl<const N: usize, T: Scalar + Serialize> Serialize for Vector<N, T> {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		serialize_array(serializer, &self.0)
+	}
+}
+
+impl<'de, const N: usize, T: Scalar + Deserialize<'de>> Deserialize<'de> for Vector<N, T> {
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		deserialize_array(deserializer).map(Vector)
+	}
+}
+
+impl<const N: usize, T: Scalar + Serialize> Serialize for Point<N, T> {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		serialize_array(serializer, &self.0)
+	}
+}
+
+impl<'de, const N: usize, T: Scalar + Deserialize<'de>> Deserialize<'de> for Point<N, T> {
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		deserialize_array(deserializer).map(Point)
+	}
+}
+
+impl<const R: usize, const C: usize, T: Scalar + Serialize> Serialize for Matrix<R, C, T> {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		serialize_array(serializer, &self.0)
+	}
+}
+
+impl<'de, const R: usize, const C: usize, T: Scalar + Deserialize<'de>> Deserialize<'de> for Matrix<R, C, T> {
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		deserialize_array(deserializer).map(Matrix)
+	}
+}
+
+impl<T: Scalar + Serialize> Serialize for Quaternion<T> {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		serialize_array(serializer, &[self.w(), self.x(), self.y(), self.z()])
+	}
+}
+
+impl<'de, T: Scalar + Deserialize<'de>> Deserialize<'de> for Quaternion<T> {
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		let [w, x, y, z] = deserialize_array(deserializer)?;
+		Ok(Quaternion::new(w, x, y, z))
+	}
+}
+
+impl<T: Scalar + Serialize> Serialize for DVector<T> {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		self.0.serialize(serializer)
+	}
+}
+
+impl<'de, T: Scalar + Deserialize<'de>> Deserialize<'de> for DVector<T> {
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		Vec::deserialize(deserializer).map(DVector)
+	}
+}
+
+impl<T: Scalar + Serialize> Serialize for DMatrix<T> {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		let mut seq = serializer.serialize_seq(Some(self.height()))?;
+		for r in 0..self.height() {
+			seq.serialize_element(&self[r])?;
+		}
+		seq.end()
+	}
+}
+
+impl<'de, T: Scalar + Deserialize<'de>> Deserialize<'de> for DMatrix<T> {
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		let rows = Vec::<Vec<T>>::deserialize(deserializer)?;
+		let cols = rows.first().map_or(0, Vec::len);
+		if let Some(row) = rows.iter().find(|row| row.len() != cols) {
+			return Err(de::Error::invalid_length(row.len(), &format!("rows of length {}", cols).as_str()));
+		}
+		Ok(DMatrix::from_row_major(rows.len(), cols, rows.concat()))
+	}
+}
+
+macro_rules! angles {
+	($($angle:ident),*) => {
+		$(
+			impl<T: Serialize> Serialize for $angle<T> {
+				fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+					self.0.serialize(serializer)
+				}
+			}
+
+			impl<'de, T: Deserialize<'de>> Deserialize<'de> for $angle<T> {
+				fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+					T::deserialize(deserializer).map($angle)
+				}
+			}
+		)*
+	};
+}
+
+angles!(Rad, Deg);
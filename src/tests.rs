@@ -9,6 +9,7 @@ fn factorial() {
 	assert_eq!(10.factorial(), 3628800);
 }
 #[test]
+#[allow(clippy::identity_op)]
 fn permutations() {
 	assert_eq!(Integer::permutations(6, 3), 6 * 5 * 4);
 	assert_eq!(Integer::permutations(6, 6), 6 * 5 * 4 * 3 * 2 * 1);
@@ -34,49 +35,74 @@ fn binomial() {
 		(4 + 5) * (4 + 5) * (4 + 5) * (4 + 5) * (4 + 5) * (4 + 5) * (4 + 5)
 	);
 }
-// #[test]
-// fn solve_mat4x3() {
-// 	let mat = Matrix3x4::from([
-// 		1.0, 1.0, 1.0,  7.0,
-// 		1.0, 2.0, 3.0, 11.0,
-// 		2.0, 1.0, 2.0, 12.0,
-// 	]);
-
-// 	assert_eq!(mat.solve(), Ok(Vector3::new(4.0, 2.0, 1.0)));
-
-// 	let mat = Matrix3x4::from([
-// 		[1.0,  2.0,  3.0, -1.0],
-// 		[2.0,  4.0,  7.0,  0.0],
-// 		[2.0,  5.0, 10.0,  5.0],
-// 	]);
-
-// 	assert_eq!(mat.solve(), Ok(Vector3::new(-5.0, -1.0, 2.0)));
-
-// 	let mat = Matrix3x4::from([
-// 		Vector4::new(1.0, 0.0, 0.0, 4.0),
-// 		[0.0, 1.0, 0.0, 2.0].into(),
-// 		(0.0, 0.0, 1.0, 0.0).into(),
-// 	]);
-
-// 	assert_eq!(mat.solve(), Ok((4.0, 2.0, 0.0).into()));
-// }
-// #[test]
-// fn solve_mat3x2() {
-// 	let mat = Matrix2x3::from([
-// 		1.0, 0.0, 69.0,
-// 		0.0, 1.0, 420.0,
-// 	]);
-
-// 	assert_eq!(mat.solve(), Ok((69.0, 420.0).into()));
-
-// 	let mat = Matrix2x3::from([
-// 		[69.0, 1337.0, 420.0],
-// 		[1337.0, 420.0, 69.0],
-// 	]);
-
-// 	assert_eq!(mat.solve(), Ok([-0.047849655, 0.31660554].into()));
-// }
 #[test]
+fn solve_mat4x3() {
+	let mat = Matrix3x4::<Float>::from([
+		1.0, 1.0, 1.0,  7.0,
+		1.0, 2.0, 3.0, 11.0,
+		2.0, 1.0, 2.0, 12.0,
+	]);
+
+	assert_approx_eq!(mat.solve().unwrap(), Vector3::new(4.0, 2.0, 1.0), epsilon = 1e-5);
+
+	let mat = Matrix3x4::<Float>::from([
+		[1.0,  2.0,  3.0, -1.0],
+		[2.0,  4.0,  7.0,  0.0],
+		[2.0,  5.0, 10.0,  5.0],
+	]);
+
+	assert_approx_eq!(mat.solve().unwrap(), Vector3::new(-5.0, -1.0, 2.0), epsilon = 1e-5);
+
+	let mat = Matrix3x4::<Float>::from([
+		Vector4::new(1.0, 0.0, 0.0, 4.0),
+		[0.0, 1.0, 0.0, 2.0].into(),
+		(0.0, 0.0, 1.0, 0.0).into(),
+	]);
+
+	assert_approx_eq!(mat.solve().unwrap(), (4.0, 2.0, 0.0).into());
+}
+#[test]
+fn solve_mat3x2() {
+	let mat = Matrix2x3::<Float>::from([
+		1.0, 0.0, 69.0,
+		0.0, 1.0, 420.0,
+	]);
+
+	assert_approx_eq!(mat.solve().unwrap(), (69.0, 420.0).into());
+
+	let mat = Matrix2x3::<Float>::from([
+		[69.0, 1337.0, 420.0],
+		[1337.0, 420.0, 69.0],
+	]);
+
+	assert_relative_eq!(mat.solve().unwrap(), [-0.04784916, 0.3166055].into(), max_relative = 1e-5);
+}
+#[test]
+fn approx_eq() {
+	let a: Float = 1.0;
+	assert!(a.abs_diff_eq(&(a + Float::EPSILON), Float::EPSILON));
+	assert!(!a.abs_diff_eq(&1.1, 0.01));
+	assert!((1e6 as Float).relative_eq(&(1e6 + 0.05), Float::EPSILON, 1e-7));
+	assert!(!(1e6 as Float).abs_diff_eq(&(1e6 + 0.05), Float::EPSILON));
+	assert_ulps_eq!(1.0 as Float, 1.0 + 2.0 * Float::EPSILON);
+	assert!(!(1.0 as Float).ulps_eq(&(1.0 + 8.0 * Float::EPSILON), 0.0, 4));
+	assert!(!(Float::NAN).ulps_eq(&Float::NAN, 1.0, 4));
+	assert!(!(1e-30 as Float).ulps_eq(&-1e-30, 0.0, 4));
+	assert!(!f32::INFINITY.ulps_eq(&f32::MAX, 0.0, 4));
+	assert!(!f64::NEG_INFINITY.ulps_eq(&f64::MIN, 0.0, 4));
+	assert!(f32::INFINITY.ulps_eq(&f32::INFINITY, 0.0, 0));
+
+	let m = Matrix3x3::<Float>::rotation(Rad(0.5));
+	assert_approx_eq!(m * m.inverse().unwrap(), Matrix3x3::identity(), epsilon = 1e-6);
+	assert_relative_eq!(Quaternion::<Float>::identity(), Quaternion::identity());
+	assert!(!Vector2::<Float>::new(1.0, 2.0).abs_diff_eq(&Vector2::new(1.0, 2.1), 0.01));
+
+	let d = DMatrix::<Float>::identity(2);
+	assert!(!d.abs_diff_eq(&DMatrix::from_row_major(1, 4, vec![1.0, 0.0, 0.0, 1.0]), 0.1));
+	assert!(!DVector::<Float>::zero(2).abs_diff_eq(&DVector::zero(3), 0.1));
+}
+#[test]
+#[allow(clippy::approx_constant)]
 fn mat_neg() {
 	assert_eq!(
 		Matrix3x3::from([
@@ -110,4 +136,915 @@ fn determinants() {
 	assert_eq!(Matrix3x3::zero().det(), 0.0);
 	assert_eq!(Matrix3x3::identity().det(), 1.0);
 	assert_eq!((Matrix3x3::identity() * 3.0).det(), Float::powi(3.0, 3));
-}
\ No newline at end of file
+}
+#[test]
+fn mixed_scalars() {
+	let a: Vector3<f32> = Vector3::new(1.0, 2.0, 2.0);
+	let b: Vector3<f64> = Vector3::new(1.0, 2.0, 2.0);
+	assert_eq!(a.mag(), 3.0);
+	assert_eq!(b.mag(), 3.0);
+	assert_eq!(2.0 * b, Vector3::new(2.0, 4.0, 4.0));
+	assert_eq!(Vector3::<f32>::zero(), Vector3::new(0.0, 0.0, 0.0));
+	assert_eq!(Vector3::<f64>::zero(), Vector3::new(0.0, 0.0, 0.0));
+	assert_eq!((Vector4::<f32>::len(), Vector4::<f64>::len()), (4, 4));
+	assert_eq!(Matrix3x3::<f32>::identity() * a, a);
+	assert_eq!(Matrix3x3::<f64>::identity() * b, b);
+	assert_eq!(Matrix2x3::<f64>::zero(), Matrix2x3::<f64>::from([0.0; 6]));
+
+	let m = Matrix3x3::from([
+		2, 0, 1,
+		1, 3, 0,
+		0, 1, 4,
+	]);
+	assert_eq!(m.det(), 25);
+	assert_eq!((m * m).det(), 625);
+}
+#[test]
+fn inverses() {
+	let m: Matrix2x2 = Matrix2x2::from([
+		2.0, 1.0,
+		0.0, 4.0,
+	]);
+	assert_eq!(m.inverse(), Some(Matrix2x2::from([
+		0.5, -0.125,
+		0.0, 0.25,
+	])));
+	assert_eq!(Matrix2x2::<Float>::zero().inverse(), None);
+
+	let mut m: Matrix3x3 = Matrix3x3::from([
+		1.0, 2.0, 0.0,
+		0.0, 1.0, 0.0,
+		0.0, 0.0, 2.0,
+	]);
+	assert!(m.invert());
+	assert_eq!(m, Matrix3x3::from([
+		1.0, -2.0, 0.0,
+		0.0, 1.0, 0.0,
+		0.0, 0.0, 0.5,
+	]));
+	let mut singular: Matrix3x3 = Matrix3x3::from([
+		1.0, 2.0, 3.0,
+		2.0, 4.0, 6.0,
+		0.0, 1.0, 1.0,
+	]);
+	assert!(!singular.invert());
+	assert_eq!(singular[1][2], 6.0);
+
+	let m: Matrix4x4 = Matrix4x4::from([
+		1.0, 0.0, 0.0, 3.0,
+		0.0, 2.0, 0.0, 0.0,
+		0.0, 0.0, 4.0, 0.0,
+		0.0, 0.0, 0.0, 1.0,
+	]);
+	assert_eq!(m.inverse(), Some(Matrix4x4::from([
+		1.0, 0.0, 0.0, -3.0,
+		0.0, 0.5, 0.0, 0.0,
+		0.0, 0.0, 0.25, 0.0,
+		0.0, 0.0, 0.0, 1.0,
+	])));
+	assert_eq!(Matrix4x4::<Float>::identity().try_inverse(), Some((Matrix4x4::identity(), 1.0)));
+	assert_eq!(m.try_inverse().map(|(_, cond)| cond), Some(16.0));
+
+	// Singularity doesn't depend on the overall magnitude.
+	let tiny = Matrix2x2::<f64>::identity() * 1e-200;
+	assert_eq!(tiny.try_inverse(), Some((Matrix2x2::<f64>::identity() * 1e200, 1.0)));
+	assert!((Matrix3x3::<f64>::identity() * 1e200).try_inverse().is_some());
+	assert_eq!(Matrix3x3::<f64>::from([1e-200, 2e-200, 3e-200, 2e-200, 4e-200, 6e-200, 0.0, 1e-200, 1e-200]).inverse(), None);
+}
+#[test]
+fn mat4x4() {
+	let m = Matrix4x4::from([
+		1, 2, 0, 0,
+		0, 1, 3, 0,
+		4, 0, 1, 0,
+		0, 0, 0, 2,
+	]);
+	assert_eq!(m.det(), 50);
+	assert_eq!(Matrix4x4::<i32>::identity().det(), 1);
+	assert_eq!((m * 2).det(), 50 * 16);
+	assert_eq!(m * Matrix4x4::<i32>::identity(), m);
+	assert_eq!(
+		m * m,
+		Matrix4x4::from([
+			1, 4, 6, 0,
+			12, 1, 6, 0,
+			8, 8, 1, 0,
+			0, 0, 0, 4,
+		]),
+	);
+}
+#[test]
+fn mat_vec_mul() {
+	let m = Matrix2x2::from([
+		1, 2,
+		3, 4,
+	]);
+	assert_eq!(m * Vector2::new(1, 1), Vector2::new(3, 7));
+	assert_eq!(Vector2::new(1, 1) * m, Vector2::new(4, 6));
+
+	let m = Matrix3x3::from([
+		1, 2, 3,
+		4, 5, 6,
+		7, 8, 9,
+	]);
+	assert_eq!(m * Vector3::new(1, 0, -1), Vector3::new(-2, -2, -2));
+	assert_eq!(Vector3::new(1, 0, -1) * m, Vector3::new(-6, -6, -6));
+
+	let m = Matrix4x4::from([
+		1, 0, 0, 5,
+		0, 1, 0, 6,
+		0, 0, 1, 7,
+		0, 0, 0, 1,
+	]);
+	assert_eq!(m * Vector4::new(1, 2, 3, 1), Vector4::new(6, 8, 10, 1));
+	assert_eq!(Vector4::new(1, 2, 3, 1) * m, Vector4::new(1, 2, 3, 39));
+
+	let m = Matrix3x4::from([
+		1, 0, 0, 5,
+		0, 2, 0, 6,
+		0, 0, 3, 7,
+	]);
+	assert_eq!(m * Vector4::new(1, 1, 1, 0), Vector3::new(1, 2, 3));
+	assert_eq!(m * Vector3::new(1, 1, 1), Vector3::new(1, 2, 3));
+	assert_eq!(m * Point3::new(1, 1, 1), Point3::new(6, 8, 10));
+	assert_eq!(Vector3::new(1, 1, 1) * m, Vector4::new(1, 2, 3, 18));
+}
+#[test]
+fn transforms() {
+	const FRAC_PI_2: Float = std::f64::consts::FRAC_PI_2 as Float;
+	let p = Vector4::new(1.0, 2.0, 3.0, 1.0);
+
+	assert_eq!(Matrix4x4::translation(Vector3::new(1.0, 1.0, 1.0)) * p, Vector4::new(2.0, 3.0, 4.0, 1.0));
+	assert_eq!(Matrix4x4::scale(Vector3::new(2.0, 3.0, 4.0)) * p, Vector4::new(2.0, 6.0, 12.0, 1.0));
+	assert_approx_eq!(Matrix4x4::rotation_x(Rad(FRAC_PI_2)) * p, Vector4::new(1.0, -3.0, 2.0, 1.0), epsilon = 1e-5);
+	assert_approx_eq!(Matrix4x4::rotation_y(Rad(FRAC_PI_2)) * p, Vector4::new(3.0, 2.0, -1.0, 1.0), epsilon = 1e-5);
+	assert_approx_eq!(Matrix4x4::rotation_z(Rad(FRAC_PI_2)) * p, Vector4::new(-2.0, 1.0, 3.0, 1.0), epsilon = 1e-5);
+	assert_approx_eq!(
+		Matrix4x4::rotation_axis(Vector3::new(0.0, 0.0, 2.0), Rad(FRAC_PI_2)) * p,
+		Matrix4x4::rotation_z(Rad(FRAC_PI_2)) * p,
+		epsilon = 1e-5,
+	);
+
+	let eye = Vector3::new(0.0, 0.0, 5.0);
+	let view = Matrix4x4::look_at_rh(eye, Vector3::zero(), Vector3::new(0.0, 1.0, 0.0));
+	assert_approx_eq!(view * Vector4::new(0.0, 0.0, 0.0, 1.0), Vector4::new(0.0, 0.0, -5.0, 1.0), epsilon = 1e-5);
+	let view = Matrix4x4::look_at_lh(eye, Vector3::zero(), Vector3::new(0.0, 1.0, 0.0));
+	assert_approx_eq!(view * Vector4::new(0.0, 0.0, 0.0, 1.0), Vector4::new(0.0, 0.0, 5.0, 1.0), epsilon = 1e-5);
+
+	let depth = |m: Matrix4x4, z: Float| {
+		let v = m * Vector4::new(0.0, 0.0, z, 1.0);
+		v[2] / v[3]
+	};
+	assert_approx_eq!(depth(Matrix4x4::perspective_rh_gl(Rad(1.0), 1.5, 0.1, 100.0), -0.1), -1.0, epsilon = 1e-5);
+	assert_approx_eq!(depth(Matrix4x4::perspective_rh_gl(Rad(1.0), 1.5, 0.1, 100.0), -100.0), 1.0, epsilon = 1e-5);
+	assert_approx_eq!(depth(Matrix4x4::perspective_rh_zo(Rad(1.0), 1.5, 0.1, 100.0), -0.1), 0.0, epsilon = 1e-5);
+	assert_approx_eq!(depth(Matrix4x4::perspective_rh_zo(Rad(1.0), 1.5, 0.1, 100.0), -100.0), 1.0, epsilon = 1e-5);
+	assert_approx_eq!(depth(Matrix4x4::perspective_lh_gl(Rad(1.0), 1.5, 0.1, 100.0), 0.1), -1.0, epsilon = 1e-5);
+	assert_approx_eq!(depth(Matrix4x4::perspective_lh_zo(Rad(1.0), 1.5, 0.1, 100.0), 100.0), 1.0, epsilon = 1e-5);
+	assert_approx_eq!(depth(Matrix4x4::orthographic_rh_gl(-1.0, 1.0, -1.0, 1.0, 1.0, 3.0), -1.0), -1.0, epsilon = 1e-5);
+	assert_approx_eq!(depth(Matrix4x4::orthographic_rh_zo(-1.0, 1.0, -1.0, 1.0, 1.0, 3.0), -1.0), 0.0, epsilon = 1e-5);
+	assert_approx_eq!(depth(Matrix4x4::orthographic_lh_gl(-1.0, 1.0, -1.0, 1.0, 1.0, 3.0), 3.0), 1.0, epsilon = 1e-5);
+	assert_approx_eq!(depth(Matrix4x4::orthographic_lh_zo(-1.0, 1.0, -1.0, 1.0, 1.0, 3.0), 3.0), 1.0, epsilon = 1e-5);
+
+	let m = Matrix3x3::translation(Vector2::new(1.0, 2.0)) * Matrix3x3::rotation(Rad(FRAC_PI_2)) * Matrix3x3::scale(Vector2::new(2.0, 2.0));
+	let v = m * Vector3::new(1.0, 0.0, 1.0);
+	assert_approx_eq!(v, Vector3::new(1.0, 4.0, 1.0), epsilon = 1e-5);
+}
+#[test]
+fn quaternions() {
+	const FRAC_PI_2: Float = std::f64::consts::FRAC_PI_2 as Float;
+	let (i, j, k) = (
+		Quaternion::new(0, 1, 0, 0),
+		Quaternion::new(0, 0, 1, 0),
+		Quaternion::new(0, 0, 0, 1),
+	);
+	assert_eq!(i * j, k);
+	assert_eq!(j * k, i);
+	assert_eq!(i * i, Quaternion::new(-1, 0, 0, 0));
+	assert_eq!(i * j * k, Quaternion::new(-1, 0, 0, 0));
+
+	let q = Quaternion::from_axis_angle(Vector3::new(0.0, 0.0, 3.0), Rad(FRAC_PI_2));
+	assert_approx_eq!(q * Vector3::new(1.0, 0.0, 0.0), Vector3::new(0.0, 1.0, 0.0), epsilon = 1e-5);
+	assert_approx_eq!(q * q.inverse(), Quaternion::identity(), epsilon = 1e-6);
+	let euler = Matrix4x4::rotation_z(Rad(1.1)) * Matrix4x4::rotation_y(Rad(-0.2)) * Matrix4x4::rotation_x(Rad(0.3));
+	let v = euler * Vector4::new(1.0, 2.0, 3.0, 0.0);
+	assert_approx_eq!(Quaternion::from_euler(Rad(0.3), Rad(-0.2), Rad(1.1)) * Vector3::new(1.0, 2.0, 3.0), Vector3::new(v[0], v[1], v[2]), epsilon = 1e-5);
+
+	let q = Quaternion::from_euler(Rad(0.3), Rad(-0.2), Rad(1.1));
+	let m = Matrix3x3::from(q);
+	assert_approx_eq!(m * Vector3::new(1.0, 2.0, 3.0), q * Vector3::new(1.0, 2.0, 3.0), epsilon = 1e-5);
+	assert_approx_eq!(Quaternion::from(m), q, epsilon = 1e-6);
+	assert_approx_eq!(Quaternion::from(Matrix4x4::from(-q)), q, epsilon = 1e-6);
+
+	let a = Quaternion::identity();
+	let b = Quaternion::from_axis_angle(Vector3::new(0.0, 1.0, 0.0), Rad(FRAC_PI_2));
+	let half = Quaternion::from_axis_angle(Vector3::new(0.0, 1.0, 0.0), Rad(FRAC_PI_2) / 2.0);
+	assert_approx_eq!(a.slerp(b, 0.5), half, epsilon = 1e-6);
+	assert_approx_eq!(a.nlerp(b, 0.5), half, epsilon = 1e-6);
+	assert_approx_eq!(a.slerp(b, 0.0), a, epsilon = 1e-6);
+	assert_approx_eq!(a.slerp(b, 1.0), b, epsilon = 1e-6);
+}
+#[test]
+fn vector_algebra() {
+	let (x, y, z) = (Vector3::new(1, 0, 0), Vector3::new(0, 1, 0), Vector3::new(0, 0, 1));
+	assert_eq!(x.cross(y), z);
+	assert_eq!(y.cross(x), -z);
+	assert_eq!(Vector3::new(1, 2, 3).dot(Vector3::new(4, -5, 6)), 12);
+	assert_eq!(Vector2::new(1, 0).perp_dot(Vector2::new(0, 1)), 1);
+	assert_eq!(Vector4::new(1, 2, 3, 4).dot(Vector4::new(1, 1, 1, 1)), 10);
+
+	assert_eq!(Vector2::new(3, -7).min(Vector2::new(1, 2)), Vector2::new(1, -7));
+	assert_eq!(Vector3::new(3, -7, 0).max(Vector3::new(1, 2, 0)), Vector3::new(3, 2, 0));
+	assert_eq!(Vector4::new(-1, 2, -3, 4).abs(), Vector4::new(1, 2, 3, 4));
+	assert_eq!(
+		Vector4::new(-5, 0, 5, 10).clamp(Vector4::new(0, 0, 0, 0), Vector4::new(4, 4, 4, 4)),
+		Vector4::new(0, 0, 4, 4),
+	);
+	assert_eq!(Vector3::new(1, 2, 3).distance_sq(Vector3::new(1, 0, 0)), 13);
+
+	let a: Vector2 = Vector2::new(2.0, 0.0);
+	let b = Vector2::new(3.0, 4.0);
+	assert_eq!(a.lerp(b, 0.5), Vector2::new(2.5, 2.0));
+	assert_eq!(a.distance(b), Vector2::new(1.0, 4.0).mag());
+	assert_eq!(b.project_onto(a), Vector2::new(3.0, 0.0));
+	assert_eq!(b.reject_from(a), Vector2::new(0.0, 4.0));
+	assert_approx_eq!(Vector3::new(1.0, 0.0, 0.0).angle_between(Vector3::new(0.0, 2.0, 0.0)), Rad(std::f64::consts::FRAC_PI_2 as Float), epsilon = 1e-6);
+	assert_eq!(Vector3::new(1.0, 0.0, 0.0).angle_between(Vector3::new(1.0, 0.0, 0.0)), Rad(0.0));
+
+	let n = Vector3::new(0.0, 1.0, 0.0);
+	assert_eq!(Vector3::new(1.0, -1.0, 0.0).reflect(n), Vector3::new(1.0, 1.0, 0.0));
+	assert_eq!(Vector3::new(0.0, -1.0, 0.0).refract(n, 1.5), Some(Vector3::new(0.0, -1.0, 0.0)));
+	assert_eq!(Vector3::new(1.0, -0.1, 0.0).normalized().refract(n, 1.5), None);
+
+	let mut v: Vector4 = Vector4::new(2.0, 0.0, 0.0, 2.0);
+	v.normalize();
+	assert_eq!(v, Vector4::new(2.0, 0.0, 0.0, 2.0).normalized());
+}
+#[test]
+fn solve_systems() {
+	let inconsistent: Matrix3x4 = Matrix3x4::from([
+		1.0, 1.0, 1.0, 1.0,
+		2.0, 2.0, 2.0, 3.0,
+		0.0, 1.0, 0.0, 1.0,
+	]);
+	assert_eq!(inconsistent.solve(), Err(SolveError::Inconsistent));
+	assert_eq!(inconsistent.solve_general(), Err(SolveError::Inconsistent));
+
+	let underdetermined: Matrix3x4 = Matrix3x4::from([
+		1.0, 1.0, 1.0, 3.0,
+		2.0, 2.0, 2.0, 6.0,
+		0.0, 1.0, -1.0, 0.0,
+	]);
+	assert_eq!(underdetermined.solve(), Err(SolveError::InfinitelyMany));
+	let general = underdetermined.solve_general().unwrap();
+	assert_eq!(general.basis.len(), 1);
+	let a: Matrix3x3 = Matrix3x3::from([
+		1.0, 1.0, 1.0,
+		2.0, 2.0, 2.0,
+		0.0, 1.0, -1.0,
+	]);
+	for t in [-2.0, 0.0, 1.5] {
+		let x = general.particular + general.basis[0] * t;
+		assert_approx_eq!(a * x, Vector3::new(3.0, 6.0, 0.0), epsilon = 1e-5);
+	}
+	assert_eq!(a.solve(Vector3::new(3.0, 6.0, 0.0)), Err(SolveError::Singular));
+
+	let a: Matrix4x4 = Matrix4x4::from([
+		0.0, 2.0, 0.0, 0.0,
+		1.0, 0.0, 0.0, 0.0,
+		0.0, 0.0, 0.0, 4.0,
+		0.0, 0.0, 1.0, 1.0,
+	]);
+	assert_eq!(a.solve(Vector4::new(2.0, 3.0, 8.0, 5.0)), Ok(Vector4::new(3.0, 1.0, 3.0, 2.0)));
+	let a: Matrix2x2 = Matrix2x2::from([
+		1e-20, 1.0,
+		1.0, 1.0,
+	]);
+	let x = a.solve(Vector2::new(1.0, 2.0)).unwrap();
+	assert_approx_eq!(x, Vector2::new(1.0, 1.0), epsilon = 1e-5);
+
+	// A large right-hand side doesn't make the coefficients look singular.
+	assert_eq!(Matrix3x3::<f32>::identity().solve(Vector3::new(1e8, 1.0, 1.0)), Ok(Vector3::new(1e8, 1.0, 1.0)));
+	assert_eq!(Matrix3x3::<f64>::identity().solve(Vector3::new(1e17, 1.0, 1.0)), Ok(Vector3::new(1e17, 1.0, 1.0)));
+	let small = Matrix3x4::<f64>::from([
+		1e-3, 0.0, 0.0, 1e6,
+		0.0, 1e-3, 0.0, 1e6,
+		0.0, 0.0, 1e-3, 1e6,
+	]);
+	assert_relative_eq!(small.solve().unwrap(), Vector3::new(1e9, 1e9, 1e9), max_relative = 1e-12);
+}
+#[test]
+fn decompositions() {
+	let a: Matrix3x3 = Matrix3x3::from([
+		2.0, 1.0, 1.0,
+		4.0, -6.0, 0.0,
+		-2.0, 7.0, 2.0,
+	]);
+	let b = Vector3::new(5.0, -2.0, 9.0);
+	let x = Vector3::new(1.0, 1.0, 2.0);
+
+	let lu = a.lu();
+	assert_approx_eq!(lu.p() * a, lu.l() * lu.u(), epsilon = 1e-3);
+	assert_approx_eq!(lu.det(), a.det(), epsilon = 1e-4);
+	assert_approx_eq!(lu.solve(b).unwrap(), x, epsilon = 1e-5);
+	assert_approx_eq!(lu.inverse().unwrap(), a.inverse().unwrap(), epsilon = 1e-3);
+
+	let qr = a.qr();
+	assert_approx_eq!(qr.q() * qr.r(), a, epsilon = 1e-3);
+	assert_approx_eq!(qr.q() * Matrix3x3::from([
+		qr.q()[0][0], qr.q()[1][0], qr.q()[2][0],
+		qr.q()[0][1], qr.q()[1][1], qr.q()[2][1],
+		qr.q()[0][2], qr.q()[1][2], qr.q()[2][2],
+	]), Matrix3x3::identity(), epsilon = 1e-3);
+	assert!((0..3).all(|r| (0..r).all(|c| qr.r()[r][c] == 0.0)));
+	assert_approx_eq!(qr.det(), a.det(), epsilon = 1e-4);
+	assert_approx_eq!(qr.solve(b).unwrap(), x, epsilon = 1e-5);
+	assert_approx_eq!(qr.inverse().unwrap(), a.inverse().unwrap(), epsilon = 1e-3);
+
+	assert!(a.cholesky().is_none());
+	let spd: Matrix3x3 = Matrix3x3::from([
+		4.0, 12.0, -16.0,
+		12.0, 37.0, -43.0,
+		-16.0, -43.0, 98.0,
+	]);
+	let cholesky = spd.cholesky().unwrap();
+	assert_eq!(cholesky.l(), Matrix3x3::from([
+		2.0, 0.0, 0.0,
+		6.0, 1.0, 0.0,
+		-8.0, 5.0, 3.0,
+	]));
+	assert_eq!(cholesky.det(), 36.0);
+	let b = spd * x;
+	assert_approx_eq!(cholesky.solve(b), x, epsilon = 1e-4);
+	assert_approx_eq!(cholesky.inverse() * spd, Matrix3x3::identity(), epsilon = 1e-3);
+
+	let singular: Matrix4x4 = Matrix4x4::from([
+		1.0, 2.0, 3.0, 4.0,
+		2.0, 4.0, 6.0, 8.0,
+		0.0, 1.0, 0.0, 1.0,
+		1.0, 0.0, 0.0, 1.0,
+	]);
+	assert_eq!(singular.lu().solve(Vector4::zero()), Err(SolveError::Singular));
+	assert_eq!(singular.qr().solve(Vector4::zero()), Err(SolveError::Singular));
+	assert_eq!(singular.lu().det(), 0.0);
+
+	let a: Matrix2x2 = Matrix2x2::from([
+		0.0, 1.0,
+		2.0, 0.0,
+	]);
+	assert_eq!(a.lu().det(), -2.0);
+	assert_eq!(a.lu().solve(Vector2::new(3.0, 4.0)), Ok(Vector2::new(2.0, 3.0)));
+	assert_approx_eq!(a.qr().det(), -2.0, epsilon = 1e-6);
+
+	// Every solver agrees on singularity, independent of how rows are scaled.
+	let a = Matrix2x2::<f32>::from([1e6, 0.0, 0.0, 1e-3]);
+	let (b, x) = (Vector2::new(1e6, 1e-3), Vector2::new(1.0, 1.0));
+	assert!(a.inverse().is_some());
+	assert_eq!(a.solve(b), Ok(x));
+	assert_eq!(a.lu().solve(b), Ok(x));
+	assert_eq!(a.qr().solve(b), Ok(x));
+	assert_eq!(DMatrix::from(a).solve(&DVector::from(vec![1e6, 1e-3])), Ok(DVector::from(vec![1.0, 1.0])));
+	let a = Matrix2x2::<f32>::from([1e6, 2e6, 1e-3, 2e-3]);
+	assert!(a.inverse().is_none());
+	assert_eq!(a.solve(b), Err(SolveError::Singular));
+	assert_eq!(a.lu().solve(b), Err(SolveError::Singular));
+	assert_eq!(a.qr().solve(b), Err(SolveError::Singular));
+}
+#[test]
+fn symmetric_eigen() {
+	let a: Matrix2x2 = Matrix2x2::from([
+		2.0, 1.0,
+		1.0, 2.0,
+	]);
+	let eigen = a.symmetric_eigen().unwrap();
+	assert_approx_eq!(eigen.values, Vector2::new(1.0, 3.0), epsilon = 1e-6);
+	for i in 0..2 {
+		let v = eigen.vectors[i];
+		assert_approx_eq!(a * v, v * eigen.values[i], epsilon = 1e-5);
+		assert_approx_eq!(v.mag(), 1.0, epsilon = 1e-6);
+	}
+	assert!(Matrix2x2::<Float>::from([1.0, 2.0, 0.0, 1.0]).symmetric_eigen().is_none());
+	let diagonal = Matrix2x2::<Float>::from([3.0, 0.0, 0.0, -1.0]).symmetric_eigen().unwrap();
+	assert_eq!(diagonal.values, Vector2::new(-1.0, 3.0));
+	assert_eq!(diagonal.vectors[0].abs(), Vector2::new(0.0, 1.0));
+
+	let a: Matrix3x3 = Matrix3x3::from([
+		4.0, 1.0, -2.0,
+		1.0, 2.0, 0.0,
+		-2.0, 0.0, 3.0,
+	]);
+	let eigen = a.symmetric_eigen().unwrap();
+	assert!(eigen.values[0] <= eigen.values[1] && eigen.values[1] <= eigen.values[2]);
+	assert_approx_eq!(eigen.values[0] + eigen.values[1] + eigen.values[2], 9.0, epsilon = 1e-5);
+	for i in 0..3 {
+		let v = eigen.vectors[i];
+		assert_approx_eq!(a * v, v * eigen.values[i], epsilon = 1e-5);
+		for j in 0..3 {
+			let expected = if i == j { 1.0 } else { 0.0 };
+			assert_approx_eq!(v.dot(eigen.vectors[j]), expected, epsilon = 1e-5);
+		}
+	}
+
+	let a: Matrix4x4 = Matrix4x4::from([
+		2.0, -1.0, 0.0, 0.0,
+		-1.0, 2.0, -1.0, 0.0,
+		0.0, -1.0, 2.0, -1.0,
+		0.0, 0.0, -1.0, 2.0,
+	]);
+	let eigen = a.symmetric_eigen().unwrap();
+	let pi = std::f64::consts::PI as Float;
+	for i in 0..4 {
+		let expected = 2.0 - 2.0 * (pi * (i + 1) as Float / 5.0).cos();
+		assert_approx_eq!(eigen.values[i], expected, epsilon = 1e-5);
+		let v = eigen.vectors[i];
+		assert_approx_eq!(a * v, v * eigen.values[i], epsilon = 1e-5);
+	}
+	assert!(Matrix4x4::<Float>::translation(Vector3::new(1.0, 0.0, 0.0)).symmetric_eigen().is_none());
+}
+#[test]
+fn svd() {
+	let a: Matrix3x3 = Matrix3x3::from([
+		2.0, 0.0, 1.0,
+		-1.0, 3.0, 0.0,
+		0.0, 1.0, 4.0,
+	]);
+	let svd = a.svd();
+	let s = svd.singular_values;
+	assert!(s[0] >= s[1] && s[1] >= s[2]);
+	for r in 0..3 {
+		for c in 0..3 {
+			let value: Float = (0..3).map(|k| svd.u[r][k] * s[k] * svd.v[c][k]).sum();
+			assert_approx_eq!(value, a[r][c], epsilon = 1e-4);
+		}
+	}
+	let inverse = a.inverse().unwrap();
+	let pinv = a.pseudo_inverse();
+	for r in 0..3 {
+		assert_approx_eq!(pinv[r], inverse[r], epsilon = 1e-4);
+	}
+
+	let diagonal = Matrix2x2::<Float>::from([4.0, 0.0, 0.0, -0.5]);
+	assert_eq!(diagonal.singular_values(), Vector2::new(4.0, 0.5));
+	assert_approx_eq!(diagonal.condition_number(), 8.0, epsilon = 1e-5);
+
+	let deficient: Matrix4x4 = Matrix4x4::from([
+		1.0, 2.0, 3.0, 4.0,
+		2.0, 4.0, 6.0, 8.0,
+		0.0, 1.0, 0.0, 1.0,
+		1.0, 3.0, 3.0, 5.0,
+	]);
+	assert_eq!(deficient.rank(1e-4), 2);
+	assert_eq!(Matrix4x4::<Float>::zero().rank(1e-4), 0);
+
+	let wide: Matrix3x4 = Matrix3x4::from([
+		1.0, 2.0, 0.0, 1.0,
+		0.0, 1.0, 1.0, 0.0,
+		1.0, 3.0, 1.0, 1.0,
+	]);
+	assert_eq!(wide.rank(1e-4), 2);
+	let pinv = wide.pseudo_inverse();
+	for r in 0..3 {
+		for c in 0..4 {
+			let value: Float = (0..4)
+				.map(|i| (0..3).map(|j| wide[r][i] * pinv[i][j] * wide[j][c]).sum::<Float>())
+				.sum();
+			assert_approx_eq!(value, wide[r][c], epsilon = 1e-4);
+		}
+	}
+}
+#[test]
+fn generic_shapes() {
+	let a = Vector::<5, i32>::from([1, 2, 3, 4, 5]);
+	let b = Vector::<5, i32>::from([5, 4, 3, 2, 1]);
+	assert_eq!(a + b, Vector::from([6; 5]));
+	assert_eq!(a.dot(b), 35);
+	assert_eq!(2 * a, a + a);
+	assert_eq!(Vector3::new(1, 2, 3).z(), 3);
+
+	let m = Matrix3x2::<i32>::from([
+		1, 2,
+		3, 4,
+		5, 6,
+	]);
+	assert_eq!(m * Vector2::new(1, -1), Vector3::new(-1, -1, -1));
+	assert_eq!(Vector3::new(1, 0, 1) * m, Vector2::new(6, 8));
+	assert_eq!(Matrix::<3, 2, i32>::identity()[1], Vector2::new(0, 1));
+	assert_eq!(Matrix3x2::<i32>::width(), 2);
+	assert_eq!(Matrix3x2::<i32>::height(), 3);
+
+	let m = Matrix2x2::<i32>::from([1, 2, 3, 4]);
+	assert_eq!(format!("{:?}", m), "┌╴    ╶┐\n│ 1  2 │\n│      │\n│ 3  4 │\n└╴    ╶┘\n");
+}
+#[test]
+fn mat_mul_shapes() {
+	let a = Matrix3x4::<i32>::from([
+		1, 2, 3, 4,
+		5, 6, 7, 8,
+		9, 10, 11, 12,
+	]);
+	let t: Matrix4x3<i32> = a.transpose();
+	assert_eq!(t[3], Vector3::new(4, 8, 12));
+	assert_eq!(t.transpose(), a);
+
+	let product: Matrix3x3<i32> = a * t;
+	assert_eq!(product[0], Vector3::new(30, 70, 110));
+	assert_eq!(product.transpose(), product);
+	let product: Matrix4x4<i32> = t * a;
+	assert_eq!(product[0], Vector4::new(107, 122, 137, 152));
+
+	let b = Matrix4x2::<i32>::from([
+		1, 0,
+		0, 1,
+		1, 0,
+		0, 1,
+	]);
+	let product: Matrix3x2<i32> = a * b;
+	assert_eq!(product, Matrix3x2::from([4, 6, 12, 14, 20, 22]));
+	assert_eq!(Matrix3x3::<i32>::identity() * a, a);
+}
+#[test]
+fn matrix_traits() {
+	fn trace_of_product<M: SquareMatrix + Clone + std::ops::Mul<Output = M>>(a: M, b: M) -> M::Scalar {
+		(a * b).trace()
+	}
+	fn adjugate_identity<M>(m: M) -> M
+	where
+		M: SquareMatrix + Clone + std::ops::Mul<Output = M> + std::ops::Sub<Output = M>
+			+ std::ops::Mul<<M as MatrixOps>::Scalar, Output = M>,
+	{
+		let det = m.det();
+		m.clone() * m.adjugate() - M::identity() * det
+	}
+
+	let a = Matrix3x3::<i32>::from([
+		2, 0, 1,
+		-1, 3, 0,
+		0, 1, 4,
+	]);
+	let b = Matrix3x3::<i32>::from([
+		1, 2, 0,
+		0, 1, 0,
+		5, 0, 1,
+	]);
+	assert_eq!(a.trace(), 9);
+	assert_eq!(trace_of_product(a, b), trace_of_product(b, a));
+	assert_eq!(a.minor(1, 0), Matrix2x2::from([0, 1, 1, 4]));
+	assert_eq!(a.cofactor(1, 0), 1);
+	assert_eq!(a.cofactor_matrix().transpose(), a.adjugate());
+	assert_eq!(adjugate_identity(a), Matrix3x3::<i32>::zero());
+	assert_eq!(Matrix2x2::<i32>::from([1, 2, 3, 4]).adjugate(), Matrix2x2::from([4, -2, -3, 1]));
+
+	let m = Matrix4x4::<i32>::from([
+		1, 2, 0, 1,
+		0, 3, 1, 0,
+		2, 0, 1, 1,
+		1, 1, 0, 2,
+	]);
+	assert_eq!(adjugate_identity(m), Matrix4x4::<i32>::zero());
+	assert_eq!(m.row(2), Vector4::new(2, 0, 1, 1));
+	assert_eq!(m.col(1), Vector4::new(2, 3, 0, 1));
+
+	let rows = Matrix2x3::<i32>::from_rows([Vector3::new(1, 2, 3), Vector3::new(4, 5, 6)]);
+	let cols = Matrix2x3::<i32>::from_cols([Vector2::new(1, 4), Vector2::new(2, 5), Vector2::new(3, 6)]);
+	assert_eq!(rows, cols);
+	assert_eq!(MatrixOps::transpose(&rows).col(1), Vector3::new(4, 5, 6));
+	assert_eq!(<Matrix2x3<i32> as MatrixOps>::width(), 3);
+}
+#[test]
+fn dynamic() {
+	use std::convert::TryFrom;
+
+	let fixed: Matrix3x3 = Matrix3x3::from([
+		4.0, 1.0, -2.0,
+		1.0, 2.0, 0.0,
+		-2.0, 0.0, 3.0,
+	]);
+	let a = DMatrix::from(fixed);
+	assert_eq!((a.height(), a.width()), (3, 3));
+	assert_approx_eq!(a.det().unwrap(), fixed.det(), epsilon = 1e-4);
+	assert_eq!(Matrix3x3::try_from(a.clone()), Ok(fixed));
+	assert!(Matrix2x2::<Float>::try_from(a.clone()).is_err());
+
+	let b = DVector::from(vec![1.0, 2.0, 3.0]);
+	let x = a.solve(&b).unwrap();
+	assert_approx_eq!(&a * &x, b, epsilon = 1e-4);
+	let expected = fixed.solve(Vector3::new(1.0, 2.0, 3.0)).unwrap();
+	assert_approx_eq!(Vector3::try_from(x.clone()).unwrap(), expected, epsilon = 1e-4);
+
+	let lu = a.lu().unwrap();
+	assert_approx_eq!(&lu.p() * &a, &lu.l() * &lu.u(), epsilon = 1e-5);
+	let qr = a.qr().unwrap();
+	assert_approx_eq!(&qr.q() * &qr.r(), a, epsilon = 1e-5);
+	assert_approx_eq!(qr.solve(&b).unwrap(), x, epsilon = 1e-4);
+	let cholesky = a.cholesky().unwrap();
+	assert_approx_eq!(cholesky.solve(&b), x, epsilon = 1e-4);
+	let inverse = a.inverse().unwrap();
+	assert_approx_eq!(&inverse * &a, DMatrix::identity(3), epsilon = 1e-5);
+
+	// Least squares fit of y = 2x + 1 through the normal equations.
+	let samples = 50;
+	let design = DMatrix::from_row_major(samples, 2, (0..samples).flat_map(|i| vec![i as Float, 1.0]).collect());
+	let y = DVector::from((0..samples).map(|i| 2.0 * i as Float + 1.0).collect::<Vec<_>>());
+	let normal = design.transpose() * design.clone();
+	let rhs = y * design;
+	let fit = normal.cholesky().unwrap().solve(&rhs);
+	assert_approx_eq!(fit[0], 2.0, epsilon = 1e-3);
+	assert_approx_eq!(fit[1], 1.0, epsilon = 1e-2);
+
+	let singular = DMatrix::<Float>::from_row_major(2, 2, vec![1.0, 2.0, 2.0, 4.0]);
+	assert_eq!(singular.solve(&DVector::zero(2)), Err(SolveError::Singular));
+
+	// Non-square systems are rejected instead of panicking, both over- and
+	// underdetermined ones.
+	let tall = DMatrix::<Float>::from_row_major(3, 2, vec![1.0, 0.0, 0.0, 1.0, 1.0, 1.0]);
+	assert_eq!(tall.solve(&DVector::zero(3)), Err(SolveError::NotSquare));
+	assert_eq!(tall.inverse(), None);
+	assert_eq!(tall.det(), None);
+	assert!(tall.lu().is_none() && tall.qr().is_none() && tall.cholesky().is_none());
+	let wide = tall.transpose();
+	assert_eq!(wide.solve(&DVector::zero(2)), Err(SolveError::NotSquare));
+	assert_eq!(wide.inverse(), None);
+	assert_eq!(wide.det(), None);
+	assert_eq!(a.solve(&DVector::zero(2)), Err(SolveError::DimensionMismatch));
+	assert_eq!(a.qr().unwrap().solve(&DVector::zero(4)), Err(SolveError::DimensionMismatch));
+	assert_eq!(&wide * &tall, DMatrix::from_row_major(2, 2, vec![2.0, 1.0, 1.0, 2.0]));
+	assert_eq!(format!("{:?}", DMatrix::from(Matrix2x2::<i32>::identity())), format!("{:?}", Matrix2x2::<i32>::identity()));
+}
+#[test]
+fn sparse() {
+	// The 1D Poisson problem: tridiagonal with 2 on the diagonal and -1 beside it.
+	let n = 100;
+	let mut coo = CooMatrix::<Float>::new(n, n);
+	for i in 0..n {
+		coo.push(i, i, 2.0);
+		if i > 0 {
+			coo.push(i, i - 1, -1.0);
+			coo.push(i - 1, i, -1.0);
+		}
+	}
+	coo.push(0, 0, 0.5);
+	coo.push(0, 0, -0.5);
+	let a = CsrMatrix::from(coo);
+	assert_eq!(a.nnz(), 3 * n - 2);
+	assert_eq!(a.get(0, 0), 2.0);
+	assert_eq!(a.get(0, 5), 0.0);
+
+	let b = DVector::from(vec![1.0; n]);
+	let cg = a.conjugate_gradient(&b, 1e-5, 1000).unwrap();
+	assert!(cg.iterations <= n);
+	assert_approx_eq!(&a * &cg.x, b, epsilon = 1e-3);
+	let dense = a.to_dense().solve(&b).unwrap();
+	assert_relative_eq!(cg.x, dense, max_relative = 1e-3);
+	assert_eq!(a.conjugate_gradient(&b, 1e-5, 3), Err(SolveError::NotConverged));
+
+	// A nonsymmetric convection-diffusion operator.
+	let mut coo = CooMatrix::<Float>::new(n, n);
+	for i in 0..n {
+		coo.push(i, i, 3.0);
+		if i > 0 {
+			coo.push(i, i - 1, -1.5);
+		}
+		if i + 1 < n {
+			coo.push(i, i + 1, -0.5);
+		}
+	}
+	let a = CsrMatrix::from(coo);
+	let solution = a.bicgstab(&b, 1e-5, 1000).unwrap();
+	assert_relative_eq!(&a * &solution.x, b, max_relative = 1e-4);
+	assert_eq!(a.bicgstab(&DVector::zero(n + 1), 1e-5, 10), Err(SolveError::DimensionMismatch));
+	assert_eq!(a.conjugate_gradient(&DVector::zero(n - 1), 1e-5, 10), Err(SolveError::DimensionMismatch));
+	let wide = CsrMatrix::from(CooMatrix::<Float>::new(2, 3));
+	assert_eq!(wide.conjugate_gradient(&DVector::zero(2), 1e-5, 10), Err(SolveError::NotSquare));
+	assert_eq!(wide.bicgstab(&DVector::zero(2), 1e-5, 10), Err(SolveError::NotSquare));
+
+	// Three nodes joined by springs, with one `Vector3` of displacement per node.
+	let k = Matrix3x3::<Float>::identity() * 10.0;
+	let mut coo = CooMatrix::new(9, 9);
+	for (i, j) in [(0, 1), (1, 2)] {
+		coo.push_block(i, i, &k);
+		coo.push_block(j, j, &k);
+		coo.push_block(i, j, &-k);
+		coo.push_block(j, i, &-k);
+	}
+	coo.push_block(0, 0, &Matrix3x3::identity());
+	let stiffness = CsrMatrix::from(coo);
+	let forces = [Vector3::new(0.0, 0.0, 0.0), Vector3::new(0.0, 0.0, 0.0), Vector3::new(1.0, -2.0, 0.5)];
+	let displacement = stiffness.conjugate_gradient(&DVector::from_blocks(&forces), 1e-6, 100).unwrap().x.to_blocks::<3>();
+	let residual = stiffness.mul_blocks(&displacement);
+	for (r, f) in residual.iter().zip(&forces) {
+		assert_approx_eq!(*r, *f, epsilon = 1e-3);
+	}
+}
+#[cfg(feature = "serde")]
+#[test]
+fn serde_round_trip() {
+	let v = Vector3::<Float>::new(1.0, -2.5, 3.0);
+	assert_eq!(serde_json::to_string(&v).unwrap(), "[1.0,-2.5,3.0]");
+	assert_eq!(serde_json::from_str::<Vector3>("[1.0,-2.5,3.0]").unwrap(), v);
+	assert!(serde_json::from_str::<Vector3>("[1.0,-2.5]").is_err());
+	assert!(serde_json::from_str::<Vector3>("[1.0,-2.5,3.0,4.0]").is_err());
+	assert_eq!(serde_json::from_str::<Point3>("[1.0,-2.5,3.0]").unwrap(), Point3::from(v));
+
+	let m = Matrix2x3::<i32>::from([1, 2, 3, 4, 5, 6]);
+	assert_eq!(serde_json::to_string(&m).unwrap(), "[[1,2,3],[4,5,6]]");
+	assert_eq!(serde_json::from_str::<Matrix2x3<i32>>("[[1,2,3],[4,5,6]]").unwrap(), m);
+	assert!(serde_json::from_str::<Matrix2x3<i32>>("[[1,2,3],[4,5]]").is_err());
+
+	let transform = Matrix4x4::<Float>::translation(Vector3::new(1.0, 2.0, 3.0)) * Matrix4x4::rotation_z(Rad(0.5));
+	let json = serde_json::to_string(&transform).unwrap();
+	assert_eq!(serde_json::from_str::<Matrix4x4>(&json).unwrap(), transform);
+	let bytes = bincode::serialize(&transform).unwrap();
+	assert_eq!(bytes.len(), 16 * std::mem::size_of::<Float>());
+	assert_eq!(bincode::deserialize::<Matrix4x4>(&bytes).unwrap(), transform);
+
+	let colour = Vector4::<Float>::new(0.25, 0.5, 0.75, 1.0);
+	assert_eq!(bincode::deserialize::<Vector4>(&bincode::serialize(&colour).unwrap()).unwrap(), colour);
+	let q = Quaternion::<Float>::from_axis_angle(Vector3::new(0.0, 1.0, 0.0), Rad(1.0));
+	assert_eq!(serde_json::from_str::<Quaternion>(&serde_json::to_string(&q).unwrap()).unwrap(), q);
+	assert_eq!(bincode::deserialize::<Quaternion>(&bincode::serialize(&q).unwrap()).unwrap(), q);
+
+	let d = DMatrix::<Float>::from_row_major(2, 3, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+	assert_eq!(serde_json::to_string(&d).unwrap(), "[[1.0,2.0,3.0],[4.0,5.0,6.0]]");
+	assert_eq!(serde_json::from_str::<DMatrix>(&serde_json::to_string(&d).unwrap()).unwrap(), d);
+	assert_eq!(bincode::deserialize::<DMatrix>(&bincode::serialize(&d).unwrap()).unwrap(), d);
+	assert!(serde_json::from_str::<DMatrix>("[[1.0,2.0],[3.0]]").is_err());
+	let dv = DVector::<Float>::from(vec![1.0, 2.0]);
+	assert_eq!(bincode::deserialize::<DVector>(&bincode::serialize(&dv).unwrap()).unwrap(), dv);
+}
+#[test]
+fn memory_layout() {
+	use std::mem::{align_of, size_of};
+
+	assert_eq!(size_of::<Vector4<f32>>(), 16);
+	assert_eq!(align_of::<Vector4<f32>>(), align_of::<f32>());
+	assert_eq!(size_of::<Matrix4x4<f32>>(), 64);
+	assert_eq!(size_of::<Matrix3x4<f64>>(), 96);
+	assert_eq!(size_of::<Quaternion<f32>>(), 16);
+
+	let mut m = Matrix2x3::<i32>::from([1, 2, 3, 4, 5, 6]);
+	let copy = m;
+	assert_eq!(m.as_slice(), &[1, 2, 3, 4, 5, 6]);
+	m.as_mut_slice()[4] = 0;
+	assert_eq!(m[1], Vector3::new(4, 0, 6));
+	assert_eq!(copy[1][1], 5);
+	assert_eq!(Vector3::new(1, 2, 3).as_slice(), &[1, 2, 3]);
+	assert_eq!(Quaternion::<i32>::new(1, 2, 3, 4).as_slice(), &[1, 2, 3, 4]);
+}
+#[cfg(feature = "bytemuck")]
+#[test]
+fn pod() {
+	let m = Matrix4x4::<f32>::translation(Vector3::new(1.0, 2.0, 3.0));
+	let bytes = m.as_bytes();
+	assert_eq!(bytes.len(), 64);
+	assert_eq!(bytes[12..16], 1.0f32.to_ne_bytes());
+	assert_eq!(bytemuck::cast::<Matrix4x4<f32>, [f32; 16]>(m)[7], 2.0);
+	assert_eq!(*bytemuck::from_bytes::<Matrix4x4<f32>>(bytes), m);
+	assert_eq!(bytemuck::cast_slice::<Vector4<f32>, f32>(&[Vector4::new(1.0, 2.0, 3.0, 4.0); 2])[5], 2.0);
+	assert_eq!(<Vector4<f32> as bytemuck::Zeroable>::zeroed(), Vector4::splat(0.0));
+	assert_eq!(Vector2::<f64>::new(1.0, 0.0).as_bytes()[..8], 1.0f64.to_ne_bytes());
+	assert_eq!(DVector::<f32>::from(vec![1.0, 2.0]).as_bytes().len(), 8);
+}
+#[test]
+fn layout_conversions() {
+	let m = Matrix2x3::<i32>::from([
+		1, 2, 3,
+		4, 5, 6,
+	]);
+	assert_eq!(m.to_rows_array(), [[1, 2, 3], [4, 5, 6]]);
+	assert_eq!(m.to_cols_array(), [[1, 4], [2, 5], [3, 6]]);
+	assert_eq!(Matrix2x3::from_cols_array(m.to_cols_array()), m);
+	assert_eq!(Matrix2x3::from(m.to_rows_array()), m);
+
+	// glTF stores node matrices as 16 column-major floats.
+	let gltf: [f32; 16] = [
+		1.0, 0.0, 0.0, 0.0,
+		0.0, 1.0, 0.0, 0.0,
+		0.0, 0.0, 1.0, 0.0,
+		5.0, 6.0, 7.0, 1.0,
+	];
+	let mut cols = [[0.0; 4]; 4];
+	cols.as_flattened_mut().copy_from_slice(&gltf);
+	let transform = Matrix4x4::from_cols_array(cols);
+	assert_eq!(transform, Matrix4x4::translation(Vector3::new(5.0, 6.0, 7.0)));
+	assert_eq!(transform.to_cols_array().as_flattened(), &gltf);
+	assert_eq!(transform.as_slice()[3], 5.0);
+
+	let d = DMatrix::<i32>::from_col_major(2, 3, vec![1, 4, 2, 5, 3, 6]);
+	assert_eq!(d, DMatrix::from(m));
+	assert_eq!(d.to_col_major(), m.to_cols_array().as_flattened());
+}
+#[test]
+fn swizzles() {
+	let v = Vector4::new(1, 2, 3, 4);
+	assert_eq!(v.xy(), Vector2::new(1, 2));
+	assert_eq!(v.xyz(), Vector3::new(1, 2, 3));
+	assert_eq!(v.zyx(), Vector3::new(3, 2, 1));
+	assert_eq!(v.xxyy(), Vector4::new(1, 1, 2, 2));
+	assert_eq!(v.wzyx(), Vector4::new(4, 3, 2, 1));
+	assert_eq!(Vector3::new(1, 2, 3).zz(), Vector2::new(3, 3));
+	assert_eq!(Vector2::new(1, 2).yxyx(), Vector4::new(2, 1, 2, 1));
+
+	assert_eq!(Vector2::new(1, 2).extend(3), Vector3::new(1, 2, 3));
+	assert_eq!(Vector3::new(1, 2, 3).extend(4), v);
+	assert_eq!(v.truncate(), Vector3::new(1, 2, 3));
+	assert_eq!(v.truncate().truncate(), Vector2::new(1, 2));
+}
+#[test]
+fn matrix_format() {
+	let m = Matrix2x2::<Float>::from([1.0, -0.25, 100.0, 2.5]);
+	assert_eq!(format!("{}", m), "┌╴          ╶┐\n│   1  -0.25 │\n│            │\n│ 100   2.5  │\n└╴          ╶┘\n");
+	assert_eq!(format!("{:.1}", m), "┌╴           ╶┐\n│   1.0  -0.2 │\n│             │\n│ 100.0   2.5 │\n└╴           ╶┘\n");
+	assert_eq!(format!("{:#}", m), "  1  -0.25\n100   2.5");
+	assert_eq!(format!("{:#6}", Matrix2x2::<i32>::identity()), "     1       0\n     0       1");
+	assert_eq!(format!("{:+.1}", m.latex()), "\\begin{bmatrix}\n\t  +1.0 & -0.2 \\\\\n\t+100.0 & +2.5\n\\end{bmatrix}");
+	assert_eq!(format!("{}", Matrix2x2::<i32>::identity().markdown()), "|    |    |\n| -: | -: |\n|  1 |  0 |\n|  0 |  1 |");
+	assert_eq!(
+		format!("{:?}", Matrix2x3::<i32>::from([1, 0, 69, 0, -1, 420])),
+		"Matrix2x3\n┌╴      ╷    ╶┐\n│ 1   0 │  69 │\n│       │     │\n│ 0  -1 │ 420 │\n└╴      ╵    ╶┘\n"
+	);
+	assert_eq!(format!("{:.3}", DMatrix::from(m)), format!("{:.3}", m));
+	assert_eq!(format!("{:?}", Matrix::<1, 2, i32>::from([[1, 2]])), "┌╴    ╶┐\n│ 1  2 │\n└╴    ╶┘\n");
+}
+#[test]
+fn parse() {
+	assert_eq!("(1, 2, 3)".parse(), Ok(Vector3::<Float>::new(1.0, 2.0, 3.0)));
+	assert_eq!("[1 2 3]".parse(), Ok(Vector3::<i32>::new(1, 2, 3)));
+	assert_eq!("[1; -2; 3.5]".parse(), Ok(Vector3::<Float>::new(1.0, -2.0, 3.5)));
+	assert_eq!(" 1.5, 2 ".parse(), Ok(Vector2::<Float>::new(1.5, 2.0)));
+	assert_eq!("[1 2; 3 4]".parse(), Ok(Matrix2x2::<i32>::from([1, 2, 3, 4])));
+	assert_eq!(
+		"[1, 2, 3\n 4, 5, 6\n]".parse(),
+		Ok(Matrix2x3::<Float>::from([1.0, 2.0, 3.0, 4.0, 5.0, 6.0])),
+	);
+	assert_eq!("[1 2; 3 4]".parse(), Ok(DMatrix::<i32>::from(Matrix2x2::from([1, 2, 3, 4]))));
+	assert_eq!("(1, 2, 3, 4, 5)".parse::<DVector<i32>>().map(|v| v.len()), Ok(5));
+
+	assert_eq!("(1, 2)".parse::<Vector3<Float>>(), Err(ParseError::Length { expected: 3, found: 2 }));
+	assert_eq!("[1 2; 3 4]".parse::<Vector4<Float>>(), Err(ParseError::NotAVector { row: 0, len: 2 }));
+	assert_eq!("[1 2; 3]".parse::<Matrix2x2<Float>>(), Err(ParseError::Columns { row: 1, expected: 2, found: 1 }));
+	assert_eq!("[1 2; 3 4]".parse::<Matrix3x2<Float>>(), Err(ParseError::Rows { expected: 3, found: 2 }));
+	assert_eq!("[1 2; 3 4 5]".parse::<DMatrix<Float>>(), Err(ParseError::Columns { row: 1, expected: 2, found: 3 }));
+	assert_eq!("(1, 2]".parse::<Vector2<Float>>(), Err(ParseError::Brackets));
+	assert_eq!(
+		"[1 2; 3 x]".parse::<Matrix2x2<Float>>(),
+		Err(ParseError::InvalidNumber { row: 1, col: 1, text: "x".to_string() }),
+	);
+	assert_eq!(
+		"[1 2; 3 x]".parse::<Matrix2x2<Float>>().unwrap_err().to_string(),
+		"invalid number \"x\" at row 1, column 1",
+	);
+}
+#[test]
+fn points() {
+	let a = Point3::<Float>::new(1.0, 2.0, 3.0);
+	let b = Point3::new(4.0, 6.0, 3.0);
+	assert_eq!(b - a, Vector3::new(3.0, 4.0, 0.0));
+	assert_eq!(a + (b - a), b);
+	assert_eq!(b - Vector3::new(3.0, 4.0, 0.0), a);
+	assert_eq!(a.distance(b), 5.0);
+	assert_eq!(a.lerp(b, 0.5), Point3::new(2.5, 4.0, 3.0));
+	assert_eq!(Point3::from(a.to_vector()), a);
+	assert_eq!(Vector3::from(Point3::<Float>::origin()), Vector3::zero());
+
+	let t = Matrix4x4::translation(Vector3::new(10.0, 0.0, 0.0));
+	assert_eq!(t * a, Point3::new(11.0, 2.0, 3.0));
+	assert_eq!(t * Vector3::new(1.0, 2.0, 3.0), Vector3::new(1.0, 2.0, 3.0));
+	let t = Matrix3x3::translation(Vector2::new(1.0, 2.0)) * Matrix3x3::scale(Vector2::new(2.0, 2.0));
+	assert_eq!(t * Point2::new(1.0, 1.0), Point2::new(3.0, 4.0));
+	assert_eq!(t * Vector2::new(1.0, 1.0), Vector2::new(2.0, 2.0));
+
+	let projection = Matrix4x4::<Float>::perspective_rh_gl(Deg(90.0), 1.0, 1.0, 10.0);
+	assert_approx_eq!(projection * Point3::new(1.0, 0.0, -1.0), Point3::new(1.0, 0.0, -1.0), epsilon = 1e-5);
+	assert_approx_eq!(projection * Point3::new(0.0, 10.0, -10.0), Point3::new(0.0, 1.0, 1.0), epsilon = 1e-5);
+}
+#[test]
+fn angles() {
+	const PI: Float = std::f64::consts::PI as Float;
+	assert_approx_eq!(Rad::from(Deg(180.0)), Rad(PI));
+	assert_approx_eq!(Deg::from(Rad(PI / 2.0)), Deg(90.0), epsilon = 1e-4);
+	assert_eq!(Deg(30.0) + Deg(60.0) * 2.0, Deg(150.0));
+	assert_eq!(Rad(3.0) / Rad(1.5), 2.0);
+	assert_eq!(-Deg(45.0), Deg(-45.0));
+
+	assert_eq!(Deg(370.0).normalized(), Deg(10.0));
+	assert_eq!(Deg(-90.0).normalized(), Deg(270.0));
+	assert_eq!(Deg(360.0).normalized(), Deg(0.0));
+	assert_eq!(Deg(270.0).normalized_signed(), Deg(-90.0));
+	assert_eq!(Deg(180.0).normalized_signed(), Deg(180.0));
+	assert_eq!(Deg(-180.0).normalized_signed(), Deg(180.0));
+	assert_eq!(Deg::<Float>(-1e-30).normalized(), Deg(0.0));
+	assert_approx_eq!(Rad(-PI / 2.0).normalized(), Rad(1.5 * PI), epsilon = 1e-5);
+	assert_approx_eq!(Rad(3.0 * PI).normalized_signed(), Rad(PI), epsilon = 1e-5);
+
+	assert_approx_eq!(Deg::<Float>(30.0).sin(), 0.5, epsilon = 1e-6);
+	assert_approx_eq!(Deg::<Float>(60.0).cos(), 0.5, epsilon = 1e-6);
+	assert_approx_eq!(Deg::<Float>::atan2(1.0, -1.0), Deg(135.0), epsilon = 1e-4);
+	assert_approx_eq!(Deg::<Float>::acos(0.5), Deg(60.0), epsilon = 1e-4);
+	assert_eq!(format!("{}", Deg(90.0)), "90°");
+	assert_eq!(format!("{:.2}", Rad(1.0)), "1.00 rad");
+
+	// Degrees and radians give the same rotations.
+	assert_approx_eq!(Matrix4x4::<Float>::rotation_z(Deg(90.0)), Matrix4x4::rotation_z(Rad(PI / 2.0)));
+	assert_approx_eq!(Quaternion::<Float>::from_euler(Deg(90.0), Rad(0.0), Deg(0.0)), Quaternion::from_axis_angle(Vector3::new(1.0, 0.0, 0.0), Rad(PI / 2.0)));
+	assert_approx_eq!(Vector2::<Float>::new(1.0, 0.0).angle_between(Vector2::new(-1.0, 1.0)), Rad::from(Deg(135.0)), epsilon = 1e-6);
+}
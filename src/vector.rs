@@ -1,7 +1,229 @@
 mod vector2;
 mod vector3;
 mod vector4;
+mod dvector;
+mod swizzle;
 
 pub use vector2::*;
 pub use vector3::*;
 pub use vector4::*;
+pub use dvector::*;
+
+use crate::{Float, Rad, Real, Scalar};
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[repr(transparent)]
+pub struct Vector<const N: usize, T = Float>(pub(crate) [T; N]);
+
+// Implemented for every scalar type, so `Vector3::<f64>::zero()` works
+// whichever type `Float` is.
+pub trait VectorOps {
+	type Scalar: crate::Scalar;
+	fn zero() -> Self;
+	fn len() -> usize;
+}
+
+impl<const N: usize, T: Scalar> VectorOps for Vector<N, T> {
+	type Scalar = T;
+	fn zero() -> Self {
+		Self([T::ZERO; N])
+	}
+	fn len() -> usize {
+		N
+	}
+}
+
+// Also inherent for `Float`, so that `Vector3::zero()` still infers its
+// scalar type.
+impl<const N: usize> Vector<N> {
+	pub const fn zero() -> Self {
+		Self([0.0; N])
+	}
+	pub const fn len() -> usize {
+		N
+	}
+}
+
+impl<const N: usize, T: Scalar> Vector<N, T> {
+	pub const fn splat(v: T) -> Self {
+		Self([v; N])
+	}
+	pub fn as_slice(&self) -> &[T] {
+		&self.0
+	}
+	pub fn as_mut_slice(&mut self) -> &mut [T] {
+		&mut self.0
+	}
+	pub fn mag_sq(&self) -> T {
+		self.dot(*self)
+	}
+	pub fn dot(&self, other: Self) -> T {
+		(0..N).map(|i| self[i] * other[i]).sum()
+	}
+	pub fn distance_sq(&self, other: Self) -> T {
+		(*self - other).mag_sq()
+	}
+	pub fn lerp(&self, other: Self, t: T) -> Self {
+		*self + (other - *self) * t
+	}
+	pub fn min(&self, other: Self) -> Self {
+		Self(std::array::from_fn(|i| self[i].min(other[i])))
+	}
+	pub fn max(&self, other: Self) -> Self {
+		Self(std::array::from_fn(|i| self[i].max(other[i])))
+	}
+	pub fn abs(&self) -> Self {
+		Self(self.0.map(T::abs))
+	}
+	pub fn clamp(&self, min: Self, max: Self) -> Self {
+		self.max(min).min(max)
+	}
+}
+
+impl<const N: usize, T: Real> Vector<N, T> {
+	pub fn mag(&self) -> T {
+		self.mag_sq().sqrt()
+	}
+	pub fn normalized(&self) -> Self {
+		*self / self.mag()
+	}
+	pub fn normalize(&mut self) {
+		*self /= self.mag();
+	}
+	pub fn distance(&self, other: Self) -> T {
+		(*self - other).mag()
+	}
+	pub fn angle_between(&self, other: Self) -> Rad<T> {
+		let cos = self.dot(other) / (self.mag() * other.mag());
+		Rad::acos(cos.max(-T::ONE).min(T::ONE))
+	}
+	pub fn project_onto(&self, other: Self) -> Self {
+		other * (self.dot(other) / other.mag_sq())
+	}
+	pub fn reject_from(&self, other: Self) -> Self {
+		*self - self.project_onto(other)
+	}
+	// `normal` is expected to be normalized.
+	pub fn reflect(&self, normal: Self) -> Self {
+		*self - normal * ((T::ONE + T::ONE) * self.dot(normal))
+	}
+	// `normal` is expected to be normalized. Returns `None` on total internal reflection.
+	pub fn refract(&self, normal: Self, eta: T) -> Option<Self> {
+		let cos = self.dot(normal);
+		let k = T::ONE - eta * eta * (T::ONE - cos * cos);
+		if k < T::ZERO {
+			return None;
+		}
+		Some(*self * eta - normal * (eta * cos + k.sqrt()))
+	}
+}
+
+impl<const N: usize, T: Scalar> From<[T; N]> for Vector<N, T> {
+	fn from(v: [T; N]) -> Self {
+		Self(v)
+	}
+}
+
+impl<const N: usize, T: Scalar> std::ops::Index<usize> for Vector<N, T> {
+	type Output = T;
+	fn index(&self, i: usize) -> &Self::Output {
+		&self.0[i]
+	}
+}
+
+impl<const N: usize, T: Scalar> std::ops::IndexMut<usize> for Vector<N, T> {
+	fn index_mut(&mut self, i: usize) -> &mut Self::Output {
+		&mut self.0[i]
+	}
+}
+
+impl<const N: usize, T: Scalar> std::ops::Neg for Vector<N, T> {
+	type Output = Self;
+	fn neg(self) -> Self::Output {
+		Self(self.0.map(|v| -v))
+	}
+}
+
+impl<const N: usize, T: Scalar> std::ops::Add for Vector<N, T> {
+	type Output = Self;
+	fn add(mut self, other: Self) -> Self::Output {
+		self += other;
+		self
+	}
+}
+
+impl<const N: usize, T: Scalar> std::ops::Sub for Vector<N, T> {
+	type Output = Self;
+	fn sub(mut self, other: Self) -> Self::Output {
+		self -= other;
+		self
+	}
+}
+
+impl<const N: usize, T: Scalar> std::ops::Mul<T> for Vector<N, T> {
+	type Output = Self;
+	fn mul(mut self, scalar: T) -> Self::Output {
+		self *= scalar;
+		self
+	}
+}
+
+macro_rules! for_all_scalar_types {
+	($t:ty) => {
+		impl<const N: usize> std::ops::Mul<Vector<N, $t>> for $t {
+			type Output = Vector<N, $t>;
+			fn mul(self, vector: Vector<N, $t>) -> Self::Output {
+				vector * self
+			}
+		}
+	};
+}
+
+for_all_scalar_types!(f32);
+for_all_scalar_types!(f64);
+for_all_scalar_types!(i8);
+for_all_scalar_types!(i16);
+for_all_scalar_types!(i32);
+for_all_scalar_types!(i64);
+for_all_scalar_types!(i128);
+for_all_scalar_types!(isize);
+
+impl<const N: usize, T: Scalar> std::ops::Div<T> for Vector<N, T> {
+	type Output = Self;
+	fn div(mut self, scalar: T) -> Self::Output {
+		self /= scalar;
+		self
+	}
+}
+
+impl<const N: usize, T: Scalar> std::ops::AddAssign for Vector<N, T> {
+	fn add_assign(&mut self, other: Self) {
+		for i in 0..N {
+			self[i] += other[i];
+		}
+	}
+}
+
+impl<const N: usize, T: Scalar> std::ops::SubAssign for Vector<N, T> {
+	fn sub_assign(&mut self, other: Self) {
+		for i in 0..N {
+			self[i] -= other[i];
+		}
+	}
+}
+
+impl<const N: usize, T: Scalar> std::ops::MulAssign<T> for Vector<N, T> {
+	fn mul_assign(&mut self, scalar: T) {
+		for v in self.0.iter_mut() {
+			*v *= scalar;
+		}
+	}
+}
+
+impl<const N: usize, T: Scalar> std::ops::DivAssign<T> for Vector<N, T> {
+	fn div_assign(&mut self, scalar: T) {
+		for v in self.0.iter_mut() {
+			*v /= scalar;
+		}
+	}
+}
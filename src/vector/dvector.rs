@@ -0,0 +1,168 @@
+use crate::{Float, Real, Scalar};
+
+use super::Vector;
+
+use std::convert::TryFrom;
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct DVector<T = Float>(pub(crate) Vec<T>);
+
+impl<T: Scalar> DVector<T> {
+	pub fn zero(len: usize) -> Self {
+		Self(vec![T::ZERO; len])
+	}
+	pub fn len(&self) -> usize {
+		self.0.len()
+	}
+	pub fn is_empty(&self) -> bool {
+		self.0.is_empty()
+	}
+	pub fn as_slice(&self) -> &[T] {
+		&self.0
+	}
+	// Concatenates per-node blocks, e.g. one `Vector3` per node of a mesh.
+	pub fn from_blocks<const N: usize>(blocks: &[Vector<N, T>]) -> Self {
+		Self(blocks.iter().flat_map(|b| b.0).collect())
+	}
+	pub fn to_blocks<const N: usize>(&self) -> Vec<Vector<N, T>> {
+		assert_eq!(self.len() % N, 0, "vector length is not a multiple of {}", N);
+		self.0.chunks(N).map(|c| Vector(std::array::from_fn(|i| c[i]))).collect()
+	}
+	pub fn mag_sq(&self) -> T {
+		self.dot(self)
+	}
+	pub fn dot(&self, other: &Self) -> T {
+		assert_eq!(self.len(), other.len(), "vector lengths differ");
+		self.0.iter().zip(&other.0).map(|(&a, &b)| a * b).sum()
+	}
+}
+
+impl<T: Real> DVector<T> {
+	pub fn mag(&self) -> T {
+		self.mag_sq().sqrt()
+	}
+	pub fn normalized(&self) -> Self {
+		self.clone() / self.mag()
+	}
+	pub fn normalize(&mut self) {
+		*self /= self.mag();
+	}
+	pub fn distance(&self, other: &Self) -> T {
+		(self.clone() - other.clone()).mag()
+	}
+}
+
+impl<T: Scalar> From<Vec<T>> for DVector<T> {
+	fn from(v: Vec<T>) -> Self {
+		Self(v)
+	}
+}
+
+impl<T: Scalar> From<&[T]> for DVector<T> {
+	fn from(v: &[T]) -> Self {
+		Self(v.to_vec())
+	}
+}
+
+impl<const N: usize, T: Scalar> From<Vector<N, T>> for DVector<T> {
+	fn from(v: Vector<N, T>) -> Self {
+		Self(v.0.to_vec())
+	}
+}
+
+// Gives the vector back unchanged if its length is not `N`.
+impl<const N: usize, T: Scalar> TryFrom<DVector<T>> for Vector<N, T> {
+	type Error = DVector<T>;
+	fn try_from(v: DVector<T>) -> Result<Self, Self::Error> {
+		if v.len() != N {
+			return Err(v);
+		}
+		Ok(Vector(std::array::from_fn(|i| v[i])))
+	}
+}
+
+impl<T: Scalar> std::ops::Index<usize> for DVector<T> {
+	type Output = T;
+	fn index(&self, i: usize) -> &Self::Output {
+		&self.0[i]
+	}
+}
+
+impl<T: Scalar> std::ops::IndexMut<usize> for DVector<T> {
+	fn index_mut(&mut self, i: usize) -> &mut Self::Output {
+		&mut self.0[i]
+	}
+}
+
+impl<T: Scalar> std::ops::Neg for DVector<T> {
+	type Output = Self;
+	fn neg(self) -> Self::Output {
+		Self(self.0.into_iter().map(|v| -v).collect())
+	}
+}
+
+impl<T: Scalar> std::ops::Add for DVector<T> {
+	type Output = Self;
+	fn add(mut self, other: Self) -> Self::Output {
+		self += other;
+		self
+	}
+}
+
+impl<T: Scalar> std::ops::Sub for DVector<T> {
+	type Output = Self;
+	fn sub(mut self, other: Self) -> Self::Output {
+		self -= other;
+		self
+	}
+}
+
+impl<T: Scalar> std::ops::Mul<T> for DVector<T> {
+	type Output = Self;
+	fn mul(mut self, scalar: T) -> Self::Output {
+		self *= scalar;
+		self
+	}
+}
+
+impl<T: Scalar> std::ops::Div<T> for DVector<T> {
+	type Output = Self;
+	fn div(mut self, scalar: T) -> Self::Output {
+		self /= scalar;
+		self
+	}
+}
+
+impl<T: Scalar> std::ops::AddAssign for DVector<T> {
+	fn add_assign(&mut self, other: Self) {
+		assert_eq!(self.len(), other.len(), "vector lengths differ");
+		for (a, b) in self.0.iter_mut().zip(other.0) {
+			*a += b;
+		}
+	}
+}
+
+impl<T: Scalar> std::ops::SubAssign for DVector<T> {
+	fn sub_assign(&mut self, other: Self) {
+		assert_eq!(self.len(), other.len(), "vector lengths differ");
+		for (a, b) in self.0.iter_mut().zip(other.0) {
+			*a -= b;
+		}
+	}
+}
+
+impl<T: Scalar> std::ops::MulAssign<T> for DVector<T> {
+	fn mul_assign(&mut self, scalar: T) {
+		for v in self.0.iter_mut() {
+			*v *= scalar;
+		}
+	}
+}
+
+impl<T: Scalar> std::ops::DivAssign<T> for DVector<T> {
+	fn div_assign(&mut self, scalar: T) {
+		for v in self.0.iter_mut() {
+			*v /= scalar;
+		}
+	}
+}
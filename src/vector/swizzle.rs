@@ -0,0 +1,507 @@
+use crate::Scalar;
+
+use super::{Vector2, Vector3, Vector4};
+
+macro_rules! swizzles {
+	($vec:ident { $($name:ident: $out:ident [$($i:expr),+];)* }) => {
+		impl<T: Scalar> $vec<T> {
+			$(
+				pub fn $name(&self) -> $out<T> {
+					$out::new($(self[$i]),+)
+				}
+			)*
+		}
+	};
+}
+
+// Every two-, three- and four-component combination of each vector's
+// components, named after `x`, `y`, `z` and `w`.
+swizzles!(Vector2 {
+	xx: Vector2 [0, 0];
+	xy: Vector2 [0, 1];
+	yx: Vector2 [1, 0];
+	yy: Vector2 [1, 1];
+	xxx: Vector3 [0, 0, 0];
+	xxy: Vector3 [0, 0, 1];
+	xyx: Vector3 [0, 1, 0];
+	xyy: Vector3 [0, 1, 1];
+	yxx: Vector3 [1, 0, 0];
+	yxy: Vector3 [1, 0, 1];
+	yyx: Vector3 [1, 1, 0];
+	yyy: Vector3 [1, 1, 1];
+	xxxx: Vector4 [0, 0, 0, 0];
+	xxxy: Vector4 [0, 0, 0, 1];
+	xxyx: Vector4 [0, 0, 1, 0];
+	xxyy: Vector4 [0, 0, 1, 1];
+	xyxx: Vector4 [0, 1, 0, 0];
+	xyxy: Vector4 [0, 1, 0, 1];
+	xyyx: Vector4 [0, 1, 1, 0];
+	xyyy: Vector4 [0, 1, 1, 1];
+	yxxx: Vector4 [1, 0, 0, 0];
+	yxxy: Vector4 [1, 0, 0, 1];
+	yxyx: Vector4 [1, 0, 1, 0];
+	yxyy: Vector4 [1, 0, 1, 1];
+	yyxx: Vector4 [1, 1, 0, 0];
+	yyxy: Vector4 [1, 1, 0, 1];
+	yyyx: Vector4 [1, 1, 1, 0];
+	yyyy: Vector4 [1, 1, 1, 1];
+});
+
+swizzles!(Vector3 {
+	xx: Vector2 [0, 0];
+	xy: Vector2 [0, 1];
+	xz: Vector2 [0, 2];
+	yx: Vector2 [1, 0];
+	yy: Vector2 [1, 1];
+	yz: Vector2 [1, 2];
+	zx: Vector2 [2, 0];
+	zy: Vector2 [2, 1];
+	zz: Vector2 [2, 2];
+	xxx: Vector3 [0, 0, 0];
+	xxy: Vector3 [0, 0, 1];
+	xxz: Vector3 [0, 0, 2];
+	xyx: Vector3 [0, 1, 0];
+	xyy: Vector3 [0, 1, 1];
+	xyz: Vector3 [0, 1, 2];
+	xzx: Vector3 [0, 2, 0];
+	xzy: Vector3 [0, 2, 1];
+	xzz: Vector3 [0, 2, 2];
+	yxx: Vector3 [1, 0, 0];
+	yxy: Vector3 [1, 0, 1];
+	yxz: Vector3 [1, 0, 2];
+	yyx: Vector3 [1, 1, 0];
+	yyy: Vector3 [1, 1, 1];
+	yyz: Vector3 [1, 1, 2];
+	yzx: Vector3 [1, 2, 0];
+	yzy: Vector3 [1, 2, 1];
+	yzz: Vector3 [1, 2, 2];
+	zxx: Vector3 [2, 0, 0];
+	zxy: Vector3 [2, 0, 1];
+	zxz: Vector3 [2, 0, 2];
+	zyx: Vector3 [2, 1, 0];
+	zyy: Vector3 [2, 1, 1];
+	zyz: Vector3 [2, 1, 2];
+	zzx: Vector3 [2, 2, 0];
+	zzy: Vector3 [2, 2, 1];
+	zzz: Vector3 [2, 2, 2];
+	xxxx: Vector4 [0, 0, 0, 0];
+	xxxy: Vector4 [0, 0, 0, 1];
+	xxxz: Vector4 [0, 0, 0, 2];
+	xxyx: Vector4 [0, 0, 1, 0];
+	xxyy: Vector4 [0, 0, 1, 1];
+	xxyz: Vector4 [0, 0, 1, 2];
+	xxzx: Vector4 [0, 0, 2, 0];
+	xxzy: Vector4 [0, 0, 2, 1];
+	xxzz: Vector4 [0, 0, 2, 2];
+	xyxx: Vector4 [0, 1, 0, 0];
+	xyxy: Vector4 [0, 1, 0, 1];
+	xyxz: Vector4 [0, 1, 0, 2];
+	xyyx: Vector4 [0, 1, 1, 0];
+	xyyy: Vector4 [0, 1, 1, 1];
+	xyyz: Vector4 [0, 1, 1, 2];
+	xyzx: Vector4 [0, 1, 2, 0];
+	xyzy: Vector4 [0, 1, 2, 1];
+	xyzz: Vector4 [0, 1, 2, 2];
+	xzxx: Vector4 [0, 2, 0, 0];
+	xzxy: Vector4 [0, 2, 0, 1];
+	xzxz: Vector4 [0, 2, 0, 2];
+	xzyx: Vector4 [0, 2, 1, 0];
+	xzyy: Vector4 [0, 2, 1, 1];
+	xzyz: Vector4 [0, 2, 1, 2];
+	xzzx: Vector4 [0, 2, 2, 0];
+	xzzy: Vector4 [0, 2, 2, 1];
+	xzzz: Vector4 [0, 2, 2, 2];
+	yxxx: Vector4 [1, 0, 0, 0];
+	yxxy: Vector4 [1, 0, 0, 1];
+	yxxz: Vector4 [1, 0, 0, 2];
+	yxyx: Vector4 [1, 0, 1, 0];
+	yxyy: Vector4 [1, 0, 1, 1];
+	yxyz: Vector4 [1, 0, 1, 2];
+	yxzx: Vector4 [1, 0, 2, 0];
+	yxzy: Vector4 [1, 0, 2, 1];
+	yxzz: Vector4 [1, 0, 2, 2];
+	yyxx: Vector4 [1, 1, 0, 0];
+	yyxy: Vector4 [1, 1, 0, 1];
+	yyxz: Vector4 [1, 1, 0, 2];
+	yyyx: Vector4 [1, 1, 1, 0];
+	yyyy: Vector4 [1, 1, 1, 1];
+	yyyz: Vector4 [1, 1, 1, 2];
+	yyzx: Vector4 [1, 1, 2, 0];
+	yyzy: Vector4 [1, 1, 2, 1];
+	yyzz: Vector4 [1, 1, 2, 2];
+	yzxx: Vector4 [1, 2, 0, 0];
+	yzxy: Vector4 [1, 2, 0, 1];
+	yzxz: Vector4 [1, 2, 0, 2];
+	yzyx: Vector4 [1, 2, 1, 0];
+	yzyy: Vector4 [1, 2, 1, 1];
+	yzyz: Vector4 [1, 2, 1, 2];
+	yzzx: Vector4 [1, 2, 2, 0];
+	yzzy: Vector4 [1, 2, 2, 1];
+	yzzz: Vector4 [1, 2, 2, 2];
+	zxxx: Vector4 [2, 0, 0, 0];
+	zxxy: Vector4 [2, 0, 0, 1];
+	zxxz: Vector4 [2, 0, 0, 2];
+	zxyx: Vector4 [2, 0, 1, 0];
+	zxyy: Vector4 [2, 0, 1, 1];
+	zxyz: Vector4 [2, 0, 1, 2];
+	zxzx: Vector4 [2, 0, 2, 0];
+	zxzy: Vector4 [2, 0, 2, 1];
+	zxzz: Vector4 [2, 0, 2, 2];
+	zyxx: Vector4 [2, 1, 0, 0];
+	zyxy: Vector4 [2, 1, 0, 1];
+	zyxz: Vector4 [2, 1, 0, 2];
+	zyyx: Vector4 [2, 1, 1, 0];
+	zyyy: Vector4 [2, 1, 1, 1];
+	zyyz: Vector4 [2, 1, 1, 2];
+	zyzx: Vector4 [2, 1, 2, 0];
+	zyzy: Vector4 [2, 1, 2, 1];
+	zyzz: Vector4 [2, 1, 2, 2];
+	zzxx: Vector4 [2, 2, 0, 0];
+	zzxy: Vector4 [2, 2, 0, 1];
+	zzxz: Vector4 [2, 2, 0, 2];
+	zzyx: Vector4 [2, 2, 1, 0];
+	zzyy: Vector4 [2, 2, 1, 1];
+	zzyz: Vector4 [2, 2, 1, 2];
+	zzzx: Vector4 [2, 2, 2, 0];
+	zzzy: Vector4 [2, 2, 2, 1];
+	zzzz: Vector4 [2, 2, 2, 2];
+});
+
+swizzles!(Vector4 {
+	xx: Vector2 [0, 0];
+	xy: Vector2 [0, 1];
+	xz: Vector2 [0, 2];
+	xw: Vector2 [0, 3];
+	yx: Vector2 [1, 0];
+	yy: Vector2 [1, 1];
+	yz: Vector2 [1, 2];
+	yw: Vector2 [1, 3];
+	zx: Vector2 [2, 0];
+	zy: Vector2 [2, 1];
+	zz: Vector2 [2, 2];
+	zw: Vector2 [2, 3];
+	wx: Vector2 [3, 0];
+	wy: Vector2 [3, 1];
+	wz: Vector2 [3, 2];
+	ww: Vector2 [3, 3];
+	xxx: Vector3 [0, 0, 0];
+	xxy: Vector3 [0, 0, 1];
+	xxz: Vector3 [0, 0, 2];
+	xxw: Vector3 [0, 0, 3];
+	xyx: Vector3 [0, 1, 0];
+	xyy: Vector3 [0, 1, 1];
+	xyz: Vector3 [0, 1, 2];
+	xyw: Vector3 [0, 1, 3];
+	xzx: Vector3 [0, 2, 0];
+	xzy: Vector3 [0, 2, 1];
+	xzz: Vector3 [0, 2, 2];
+	xzw: Vector3 [0, 2, 3];
+	xwx: Vector3 [0, 3, 0];
+	xwy: Vector3 [0, 3, 1];
+	xwz: Vector3 [0, 3, 2];
+	xww: Vector3 [0, 3, 3];
+	yxx: Vector3 [1, 0, 0];
+	yxy: Vector3 [1, 0, 1];
+	yxz: Vector3 [1, 0, 2];
+	yxw: Vector3 [1, 0, 3];
+	yyx: Vector3 [1, 1, 0];
+	yyy: Vector3 [1, 1, 1];
+	yyz: Vector3 [1, 1, 2];
+	yyw: Vector3 [1, 1, 3];
+	yzx: Vector3 [1, 2, 0];
+	yzy: Vector3 [1, 2, 1];
+	yzz: Vector3 [1, 2, 2];
+	yzw: Vector3 [1, 2, 3];
+	ywx: Vector3 [1, 3, 0];
+	ywy: Vector3 [1, 3, 1];
+	ywz: Vector3 [1, 3, 2];
+	yww: Vector3 [1, 3, 3];
+	zxx: Vector3 [2, 0, 0];
+	zxy: Vector3 [2, 0, 1];
+	zxz: Vector3 [2, 0, 2];
+	zxw: Vector3 [2, 0, 3];
+	zyx: Vector3 [2, 1, 0];
+	zyy: Vector3 [2, 1, 1];
+	zyz: Vector3 [2, 1, 2];
+	zyw: Vector3 [2, 1, 3];
+	zzx: Vector3 [2, 2, 0];
+	zzy: Vector3 [2, 2, 1];
+	zzz: Vector3 [2, 2, 2];
+	zzw: Vector3 [2, 2, 3];
+	zwx: Vector3 [2, 3, 0];
+	zwy: Vector3 [2, 3, 1];
+	zwz: Vector3 [2, 3, 2];
+	zww: Vector3 [2, 3, 3];
+	wxx: Vector3 [3, 0, 0];
+	wxy: Vector3 [3, 0, 1];
+	wxz: Vector3 [3, 0, 2];
+	wxw: Vector3 [3, 0, 3];
+	wyx: Vector3 [3, 1, 0];
+	wyy: Vector3 [3, 1, 1];
+	wyz: Vector3 [3, 1, 2];
+	wyw: Vector3 [3, 1, 3];
+	wzx: Vector3 [3, 2, 0];
+	wzy: Vector3 [3, 2, 1];
+	wzz: Vector3 [3, 2, 2];
+	wzw: Vector3 [3, 2, 3];
+	wwx: Vector3 [3, 3, 0];
+	wwy: Vector3 [3, 3, 1];
+	wwz: Vector3 [3, 3, 2];
+	www: Vector3 [3, 3, 3];
+	xxxx: Vector4 [0, 0, 0, 0];
+	xxxy: Vector4 [0, 0, 0, 1];
+	xxxz: Vector4 [0, 0, 0, 2];
+	xxxw: Vector4 [0, 0, 0, 3];
+	xxyx: Vector4 [0, 0, 1, 0];
+	xxyy: Vector4 [0, 0, 1, 1];
+	xxyz: Vector4 [0, 0, 1, 2];
+	xxyw: Vector4 [0, 0, 1, 3];
+	xxzx: Vector4 [0, 0, 2, 0];
+	xxzy: Vector4 [0, 0, 2, 1];
+	xxzz: Vector4 [0, 0, 2, 2];
+	xxzw: Vector4 [0, 0, 2, 3];
+	xxwx: Vector4 [0, 0, 3, 0];
+	xxwy: Vector4 [0, 0, 3, 1];
+	xxwz: Vector4 [0, 0, 3, 2];
+	xxww: Vector4 [0, 0, 3, 3];
+	xyxx: Vector4 [0, 1, 0, 0];
+	xyxy: Vector4 [0, 1, 0, 1];
+	xyxz: Vector4 [0, 1, 0, 2];
+	xyxw: Vector4 [0, 1, 0, 3];
+	xyyx: Vector4 [0, 1, 1, 0];
+	xyyy: Vector4 [0, 1, 1, 1];
+	xyyz: Vector4 [0, 1, 1, 2];
+	xyyw: Vector4 [0, 1, 1, 3];
+	xyzx: Vector4 [0, 1, 2, 0];
+	xyzy: Vector4 [0, 1, 2, 1];
+	xyzz: Vector4 [0, 1, 2, 2];
+	xyzw: Vector4 [0, 1, 2, 3];
+	xywx: Vector4 [0, 1, 3, 0];
+	xywy: Vector4 [0, 1, 3, 1];
+	xywz: Vector4 [0, 1, 3, 2];
+	xyww: Vector4 [0, 1, 3, 3];
+	xzxx: Vector4 [0, 2, 0, 0];
+	xzxy: Vector4 [0, 2, 0, 1];
+	xzxz: Vector4 [0, 2, 0, 2];
+	xzxw: Vector4 [0, 2, 0, 3];
+	xzyx: Vector4 [0, 2, 1, 0];
+	xzyy: Vector4 [0, 2, 1, 1];
+	xzyz: Vector4 [0, 2, 1, 2];
+	xzyw: Vector4 [0, 2, 1, 3];
+	xzzx: Vector4 [0, 2, 2, 0];
+	xzzy: Vector4 [0, 2, 2, 1];
+	xzzz: Vector4 [0, 2, 2, 2];
+	xzzw: Vector4 [0, 2, 2, 3];
+	xzwx: Vector4 [0, 2, 3, 0];
+	xzwy: Vector4 [0, 2, 3, 1];
+	xzwz: Vector4 [0, 2, 3, 2];
+	xzww: Vector4 [0, 2, 3, 3];
+	xwxx: Vector4 [0, 3, 0, 0];
+	xwxy: Vector4 [0, 3, 0, 1];
+	xwxz: Vector4 [0, 3, 0, 2];
+	xwxw: Vector4 [0, 3, 0, 3];
+	xwyx: Vector4 [0, 3, 1, 0];
+	xwyy: Vector4 [0, 3, 1, 1];
+	xwyz: Vector4 [0, 3, 1, 2];
+	xwyw: Vector4 [0, 3, 1, 3];
+	xwzx: Vector4 [0, 3, 2, 0];
+	xwzy: Vector4 [0, 3, 2, 1];
+	xwzz: Vector4 [0, 3, 2, 2];
+	xwzw: Vector4 [0, 3, 2, 3];
+	xwwx: Vector4 [0, 3, 3, 0];
+	xwwy: Vector4 [0, 3, 3, 1];
+	xwwz: Vector4 [0, 3, 3, 2];
+	xwww: Vector4 [0, 3, 3, 3];
+	yxxx: Vector4 [1, 0, 0, 0];
+	yxxy: Vector4 [1, 0, 0, 1];
+	yxxz: Vector4 [1, 0, 0, 2];
+	yxxw: Vector4 [1, 0, 0, 3];
+	yxyx: Vector4 [1, 0, 1, 0];
+	yxyy: Vector4 [1, 0, 1, 1];
+	yxyz: Vector4 [1, 0, 1, 2];
+	yxyw: Vector4 [1, 0, 1, 3];
+	yxzx: Vector4 [1, 0, 2, 0];
+	yxzy: Vector4 [1, 0, 2, 1];
+	yxzz: Vector4 [1, 0, 2, 2];
+	yxzw: Vector4 [1, 0, 2, 3];
+	yxwx: Vector4 [1, 0, 3, 0];
+	yxwy: Vector4 [1, 0, 3, 1];
+	yxwz: Vector4 [1, 0, 3, 2];
+	yxww: Vector4 [1, 0, 3, 3];
+	yyxx: Vector4 [1, 1, 0, 0];
+	yyxy: Vector4 [1, 1, 0, 1];
+	yyxz: Vector4 [1, 1, 0, 2];
+	yyxw: Vector4 [1, 1, 0, 3];
+	yyyx: Vector4 [1, 1, 1, 0];
+	yyyy: Vector4 [1, 1, 1, 1];
+	yyyz: Vector4 [1, 1, 1, 2];
+	yyyw: Vector4 [1, 1, 1, 3];
+	yyzx: Vector4 [1, 1, 2, 0];
+	yyzy: Vector4 [1, 1, 2, 1];
+	yyzz: Vector4 [1, 1, 2, 2];
+	yyzw: Vector4 [1, 1, 2, 3];
+	yywx: Vector4 [1, 1, 3, 0];
+	yywy: Vector4 [1, 1, 3, 1];
+	yywz: Vector4 [1, 1, 3, 2];
+	yyww: Vector4 [1, 1, 3, 3];
+	yzxx: Vector4 [1, 2, 0, 0];
+	yzxy: Vector4 [1, 2, 0, 1];
+	yzxz: Vector4 [1, 2, 0, 2];
+	yzxw: Vector4 [1, 2, 0, 3];
+	yzyx: Vector4 [1, 2, 1, 0];
+	yzyy: Vector4 [1, 2, 1, 1];
+	yzyz: Vector4 [1, 2, 1, 2];
+	yzyw: Vector4 [1, 2, 1, 3];
+	yzzx: Vector4 [1, 2, 2, 0];
+	yzzy: Vector4 [1, 2, 2, 1];
+	yzzz: Vector4 [1, 2, 2, 2];
+	yzzw: Vector4 [1, 2, 2, 3];
+	yzwx: Vector4 [1, 2, 3, 0];
+	yzwy: Vector4 [1, 2, 3, 1];
+	yzwz: Vector4 [1, 2, 3, 2];
+	yzww: Vector4 [1, 2, 3, 3];
+	ywxx: Vector4 [1, 3, 0, 0];
+	ywxy: Vector4 [1, 3, 0, 1];
+	ywxz: Vector4 [1, 3, 0, 2];
+	ywxw: Vector4 [1, 3, 0, 3];
+	ywyx: Vector4 [1, 3, 1, 0];
+	ywyy: Vector4 [1, 3, 1, 1];
+	ywyz: Vector4 [1, 3, 1, 2];
+	ywyw: Vector4 [1, 3, 1, 3];
+	ywzx: Vector4 [1, 3, 2, 0];
+	ywzy: Vector4 [1, 3, 2, 1];
+	ywzz: Vector4 [1, 3, 2, 2];
+	ywzw: Vector4 [1, 3, 2, 3];
+	ywwx: Vector4 [1, 3, 3, 0];
+	ywwy: Vector4 [1, 3, 3, 1];
+	ywwz: Vector4 [1, 3, 3, 2];
+	ywww: Vector4 [1, 3, 3, 3];
+	zxxx: Vector4 [2, 0, 0, 0];
+	zxxy: Vector4 [2, 0, 0, 1];
+	zxxz: Vector4 [2, 0, 0, 2];
+	zxxw: Vector4 [2, 0, 0, 3];
+	zxyx: Vector4 [2, 0, 1, 0];
+	zxyy: Vector4 [2, 0, 1, 1];
+	zxyz: Vector4 [2, 0, 1, 2];
+	zxyw: Vector4 [2, 0, 1, 3];
+	zxzx: Vector4 [2, 0, 2, 0];
+	zxzy: Vector4 [2, 0, 2, 1];
+	zxzz: Vector4 [2, 0, 2, 2];
+	zxzw: Vector4 [2, 0, 2, 3];
+	zxwx: Vector4 [2, 0, 3, 0];
+	zxwy: Vector4 [2, 0, 3, 1];
+	zxwz: Vector4 [2, 0, 3, 2];
+	zxww: Vector4 [2, 0, 3, 3];
+	zyxx: Vector4 [2, 1, 0, 0];
+	zyxy: Vector4 [2, 1, 0, 1];
+	zyxz: Vector4 [2, 1, 0, 2];
+	zyxw: Vector4 [2, 1, 0, 3];
+	zyyx: Vector4 [2, 1, 1, 0];
+	zyyy: Vector4 [2, 1, 1, 1];
+	zyyz: Vector4 [2, 1, 1, 2];
+	zyyw: Vector4 [2, 1, 1, 3];
+	zyzx: Vector4 [2, 1, 2, 0];
+	zyzy: Vector4 [2, 1, 2, 1];
+	zyzz: Vector4 [2, 1, 2, 2];
+	zyzw: Vector4 [2, 1, 2, 3];
+	zywx: Vector4 [2, 1, 3, 0];
+	zywy: Vector4 [2, 1, 3, 1];
+	zywz: Vector4 [2, 1, 3, 2];
+	zyww: Vector4 [2, 1, 3, 3];
+	zzxx: Vector4 [2, 2, 0, 0];
+	zzxy: Vector4 [2, 2, 0, 1];
+	zzxz: Vector4 [2, 2, 0, 2];
+	zzxw: Vector4 [2, 2, 0, 3];
+	zzyx: Vector4 [2, 2, 1, 0];
+	zzyy: Vector4 [2, 2, 1, 1];
+	zzyz: Vector4 [2, 2, 1, 2];
+	zzyw: Vector4 [2, 2, 1, 3];
+	zzzx: Vector4 [2, 2, 2, 0];
+	zzzy: Vector4 [2, 2, 2, 1];
+	zzzz: Vector4 [2, 2, 2, 2];
+	zzzw: Vector4 [2, 2, 2, 3];
+	zzwx: Vector4 [2, 2, 3, 0];
+	zzwy: Vector4 [2, 2, 3, 1];
+	zzwz: Vector4 [2, 2, 3, 2];
+	zzww: Vector4 [2, 2, 3, 3];
+	zwxx: Vector4 [2, 3, 0, 0];
+	zwxy: Vector4 [2, 3, 0, 1];
+	zwxz: Vector4 [2, 3, 0, 2];
+	zwxw: Vector4 [2, 3, 0, 3];
+	zwyx: Vector4 [2, 3, 1, 0];
+	zwyy: Vector4 [2, 3, 1, 1];
+	zwyz: Vector4 [2, 3, 1, 2];
+	zwyw: Vector4 [2, 3, 1, 3];
+	zwzx: Vector4 [2, 3, 2, 0];
+	zwzy: Vector4 [2, 3, 2, 1];
+	zwzz: Vector4 [2, 3, 2, 2];
+	zwzw: Vector4 [2, 3, 2, 3];
+	zwwx: Vector4 [2, 3, 3, 0];
+	zwwy: Vector4 [2, 3, 3, 1];
+	zwwz: Vector4 [2, 3, 3, 2];
+	zwww: Vector4 [2, 3, 3, 3];
+	wxxx: Vector4 [3, 0, 0, 0];
+	wxxy: Vector4 [3, 0, 0, 1];
+	wxxz: Vector4 [3, 0, 0, 2];
+	wxxw: Vector4 [3, 0, 0, 3];
+	wxyx: Vector4 [3, 0, 1, 0];
+	wxyy: Vector4 [3, 0, 1, 1];
+	wxyz: Vector4 [3, 0, 1, 2];
+	wxyw: Vector4 [3, 0, 1, 3];
+	wxzx: Vector4 [3, 0, 2, 0];
+	wxzy: Vector4 [3, 0, 2, 1];
+	wxzz: Vector4 [3, 0, 2, 2];
+	wxzw: Vector4 [3, 0, 2, 3];
+	wxwx: Vector4 [3, 0, 3, 0];
+	wxwy: Vector4 [3, 0, 3, 1];
+	wxwz: Vector4 [3, 0, 3, 2];
+	wxww: Vector4 [3, 0, 3, 3];
+	wyxx: Vector4 [3, 1, 0, 0];
+	wyxy: Vector4 [3, 1, 0, 1];
+	wyxz: Vector4 [3, 1, 0, 2];
+	wyxw: Vector4 [3, 1, 0, 3];
+	wyyx: Vector4 [3, 1, 1, 0];
+	wyyy: Vector4 [3, 1, 1, 1];
+	wyyz: Vector4 [3, 1, 1, 2];
+	wyyw: Vector4 [3, 1, 1, 3];
+	wyzx: Vector4 [3, 1, 2, 0];
+	wyzy: Vector4 [3, 1, 2, 1];
+	wyzz: Vector4 [3, 1, 2, 2];
+	wyzw: Vector4 [3, 1, 2, 3];
+	wywx: Vector4 [3, 1, 3, 0];
+	wywy: Vector4 [3, 1, 3, 1];
+	wywz: Vector4 [3, 1, 3, 2];
+	wyww: Vector4 [3, 1, 3, 3];
+	wzxx: Vector4 [3, 2, 0, 0];
+	wzxy: Vector4 [3, 2, 0, 1];
+	wzxz: Vector4 [3, 2, 0, 2];
+	wzxw: Vector4 [3, 2, 0, 3];
+	wzyx: Vector4 [3, 2, 1, 0];
+	wzyy: Vector4 [3, 2, 1, 1];
+	wzyz: Vector4 [3, 2, 1, 2];
+	wzyw: Vector4 [3, 2, 1, 3];
+	wzzx: Vector4 [3, 2, 2, 0];
+	wzzy: Vector4 [3, 2, 2, 1];
+	wzzz: Vector4 [3, 2, 2, 2];
+	wzzw: Vector4 [3, 2, 2, 3];
+	wzwx: Vector4 [3, 2, 3, 0];
+	wzwy: Vector4 [3, 2, 3, 1];
+	wzwz: Vector4 [3, 2, 3, 2];
+	wzww: Vector4 [3, 2, 3, 3];
+	wwxx: Vector4 [3, 3, 0, 0];
+	wwxy: Vector4 [3, 3, 0, 1];
+	wwxz: Vector4 [3, 3, 0, 2];
+	wwxw: Vector4 [3, 3, 0, 3];
+	wwyx: Vector4 [3, 3, 1, 0];
+	wwyy: Vector4 [3, 3, 1, 1];
+	wwyz: Vector4 [3, 3, 1, 2];
+	wwyw: Vector4 [3, 3, 1, 3];
+	wwzx: Vector4 [3, 3, 2, 0];
+	wwzy: Vector4 [3, 3, 2, 1];
+	wwzz: Vector4 [3, 3, 2, 2];
+	wwzw: Vector4 [3, 3, 2, 3];
+	wwwx: Vector4 [3, 3, 3, 0];
+	wwwy: Vector4 [3, 3, 3, 1];
+	wwwz: Vector4 [3, 3, 3, 2];
+	wwww: Vector4 [3, 3, 3, 3];
+});
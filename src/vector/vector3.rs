@@ -1,62 +1,48 @@
-use crate::Float;
+use crate::{Float, Scalar};
 
-#[derive(Debug, PartialEq, Clone, Copy)]
-pub struct Vector3([Float; 3]);
+use super::{Vector, Vector2, Vector4};
 
-impl Vector3 {
-	pub const fn new(v0: Float, v1: Float, v2: Float) -> Self {
+pub type Vector3<T = Float> = Vector<3, T>;
+
+impl<T: Scalar> Vector3<T> {
+	pub const fn new(v0: T, v1: T, v2: T) -> Self {
 		Self([v0, v1, v2])
 	}
-	pub const fn zero() -> Self {
-		Self([0.0, 0.0, 0.0])
-	}
-	pub fn x(&self) -> Float {
+	pub fn x(&self) -> T {
 		self[0]
 	}
-	pub fn y(&self) -> Float {
+	pub fn y(&self) -> T {
 		self[1]
 	}
-	pub fn z(&self) -> Float {
+	pub fn z(&self) -> T {
 		self[2]
 	}
-	pub fn r(&self) -> Float {
+	pub fn r(&self) -> T {
 		self[0]
 	}
-	pub fn g(&self) -> Float {
+	pub fn g(&self) -> T {
 		self[1]
 	}
-	pub fn b(&self) -> Float {
+	pub fn b(&self) -> T {
 		self[2]
 	}
-	pub const fn len() -> usize {
-		3
+	pub fn extend(&self, w: T) -> Vector4<T> {
+		Vector4::new(self[0], self[1], self[2], w)
 	}
-	pub fn mag_sq(&self) -> Float {
-		self[0] * self[0] +
-		self[1] * self[1] +
-		self[2] * self[2]
+	pub fn truncate(&self) -> Vector2<T> {
+		Vector2::new(self[0], self[1])
 	}
-	pub fn mag(&self) -> Float {
-		self.mag_sq().sqrt()
-	}
-	pub fn normalized(&self) -> Self {
-		let mag = self.mag();
+	pub fn cross(&self, other: Self) -> Self {
 		Self ([
-			self[0] / mag,
-			self[1] / mag,
-			self[2] / mag,
+			self[1] * other[2] - self[2] * other[1],
+			self[2] * other[0] - self[0] * other[2],
+			self[0] * other[1] - self[1] * other[0],
 		])
 	}
-	pub fn normalize(&mut self) {
-		let mag = self.mag();
-		self[0] /= mag;
-		self[1] /= mag;
-		self[2] /= mag;
-	}
 }
 
-impl From<(Float, Float, Float)> for Vector3 {
-	fn from(v: (Float, Float, Float)) -> Self {
+impl<T: Scalar> From<(T, T, T)> for Vector3<T> {
+	fn from(v: (T, T, T)) -> Self {
 		Self ([
 			v.0,
 			v.1,
@@ -64,116 +50,3 @@ impl From<(Float, Float, Float)> for Vector3 {
 		])
 	}
 }
-
-impl From<[Float; 3]> for Vector3 {
-	fn from(v: [Float; 3]) -> Self {
-		Self(v)
-	}
-}
-
-impl std::ops::Index<usize> for Vector3 {
-	type Output = Float;
-	fn index(&self, i: usize) -> &Self::Output {
-		&self.0[i]
-	}
-}
-
-impl std::ops::IndexMut<usize> for Vector3 {
-	fn index_mut(&mut self, i: usize) -> &mut Self::Output {
-		&mut self.0[i]
-	}
-}
-
-impl std::ops::Neg for Vector3 {
-	type Output = Vector3;
-	fn neg(self) -> Self::Output {
-		[-self[0], -self[1], -self[2]].into()
-	}
-}
-
-impl std::ops::Add for Vector3 {
-	type Output = Self;
-	fn add(self, other: Self) -> Self::Output {
-		Self ([
-			self[0] + other[0],
-			self[1] + other[1],
-			self[2] + other[2],
-		])
-	}
-}
-
-impl std::ops::Sub for Vector3 {
-	type Output = Self;
-	fn sub(self, other: Self) -> Self::Output {
-		Self ([
-			self[0] - other[0],
-			self[1] - other[1],
-			self[2] - other[2],
-		])
-	}
-}
-
-impl std::ops::Mul<Float> for Vector3 {
-	type Output = Self;
-	fn mul(self, scalar: Float) -> Self::Output {
-		[
-			self[0] * scalar,
-			self[1] * scalar,
-			self[2] * scalar,
-		].into()
-	}
-}
-
-impl std::ops::Mul<Vector3> for Float {
-	type Output = Vector3;
-	fn mul(self, vector: Vector3) -> Self::Output {
-		[
-			vector[0] * self,
-			vector[1] * self,
-			vector[2] * self,
-		].into()
-	}
-}
-
-impl std::ops::Div<Float> for Vector3 {
-	type Output = Self;
-	fn div(self, scalar: Float) -> Self::Output {
-		Self ([
-			self[0] / scalar,
-			self[1] / scalar,
-			self[2] / scalar,
-		])
-	}
-}
-
-impl std::ops::AddAssign for Vector3 {
-	fn add_assign(&mut self, other: Self) {
-		self[0] += other[0];
-		self[1] += other[1];
-		self[2] += other[2];
-	}
-}
-
-impl std::ops::SubAssign for Vector3 {
-	fn sub_assign(&mut self, other: Self) {
-		self[0] -= other[0];
-		self[1] -= other[1];
-		self[2] -= other[2];
-	}
-}
-
-impl std::ops::MulAssign<Float> for Vector3 {
-	fn mul_assign(&mut self, scalar: Float) {
-		self[0] *= scalar;
-		self[1] *= scalar;
-		self[2] *= scalar;
-	}
-}
-
-impl std::ops::DivAssign<Float> for Vector3 {
-	fn div_assign(&mut self, scalar: Float) {
-		self[0] /= scalar;
-		self[1] /= scalar;
-		self[2] /= scalar;
-	}
-}
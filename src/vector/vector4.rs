@@ -1,70 +1,44 @@
-use crate::Float;
+use crate::{Float, Scalar};
 
-#[derive(Debug, PartialEq, Clone, Copy)]
-pub struct Vector4([Float; 4]);
+use super::{Vector, Vector3};
 
-impl Vector4 {
-	pub const fn new(v0: Float, v1: Float, v2: Float, v3: Float) -> Self {
+pub type Vector4<T = Float> = Vector<4, T>;
+
+impl<T: Scalar> Vector4<T> {
+	pub const fn new(v0: T, v1: T, v2: T, v3: T) -> Self {
 		Self([v0, v1, v2, v3])
 	}
-	pub const fn zero() -> Self {
-		Self([0.0, 0.0, 0.0, 0.0])
-	}
-	pub fn x(&self) -> Float {
+	pub fn x(&self) -> T {
 		self[0]
 	}
-	pub fn y(&self) -> Float {
+	pub fn y(&self) -> T {
 		self[1]
 	}
-	pub fn z(&self) -> Float {
+	pub fn z(&self) -> T {
 		self[2]
 	}
-	pub fn w(&self) -> Float {
+	pub fn w(&self) -> T {
 		self[3]
 	}
-	pub fn r(&self) -> Float {
+	pub fn r(&self) -> T {
 		self[0]
 	}
-	pub fn g(&self) -> Float {
+	pub fn g(&self) -> T {
 		self[1]
 	}
-	pub fn b(&self) -> Float {
+	pub fn b(&self) -> T {
 		self[2]
 	}
-	pub fn a(&self) -> Float {
+	pub fn a(&self) -> T {
 		self[3]
 	}
-	pub const fn len() -> usize {
-		4
-	}
-	pub fn mag_sq(&self) -> Float {
-		self[0] * self[0] +
-		self[1] * self[1] +
-		self[2] * self[2] +
-		self[3] * self[3]
-	}
-	pub fn mag(&self) -> Float {
-		self.mag_sq().sqrt()
-	}
-	pub fn normalized(&self) -> Self {
-		let mag = self.mag();
-		Self ([
-			self[0] / mag,
-			self[1] / mag,
-			self[2] / mag,
-			self[3] / mag,
-		])
-	}
-	pub fn normalize(&mut self) {
-		let mag = self.mag();
-		self[0] /= mag;
-		self[1] /= mag;
-		self[2] /= mag;
+	pub fn truncate(&self) -> Vector3<T> {
+		Vector3::new(self[0], self[1], self[2])
 	}
 }
 
-impl From<(Float, Float, Float, Float)> for Vector4 {
-	fn from(v: (Float, Float, Float, Float)) -> Self {
+impl<T: Scalar> From<(T, T, T, T)> for Vector4<T> {
+	fn from(v: (T, T, T, T)) -> Self {
 		Self ([
 			v.0,
 			v.1,
@@ -73,113 +47,3 @@ impl From<(Float, Float, Float, Float)> for Vector4 {
 		])
 	}
 }
-
-impl From<[Float; 4]> for Vector4 {
-	fn from(v: [Float; 4]) -> Self {
-		Self(v)
-	}
-}
-
-impl std::ops::Index<usize> for Vector4 {
-	type Output = Float;
-	fn index(&self, i: usize) -> &Self::Output {
-		&self.0[i]
-	}
-}
-
-impl std::ops::IndexMut<usize> for Vector4 {
-	fn index_mut(&mut self, i: usize) -> &mut Self::Output {
-		&mut self.0[i]
-	}
-}
-
-impl std::ops::Neg for Vector4 {
-	type Output = Vector4;
-	fn neg(self) -> Self::Output {
-		[-self[0], -self[1], -self[2], -self[3]].into()
-	}
-}
-
-impl std::ops::Add for Vector4 {
-	type Output = Self;
-	fn add(self, other: Self) -> Self::Output {
-		Self ([
-			self[0] + other[0],
-			self[1] + other[1],
-			self[2] + other[2],
-			self[3] + other[3],
-		])
-	}
-}
-
-impl std::ops::Sub for Vector4 {
-	type Output = Self;
-	fn sub(self, other: Self) -> Self::Output {
-		Self ([
-			self[0] - other[0],
-			self[1] - other[1],
-			self[2] - other[2],
-			self[3] - other[3],
-		])
-	}
-}
-
-impl std::ops::Mul<Float> for Vector4 {
-	type Output = Self;
-	fn mul(self, scalar: Float) -> Self::Output {
-		Self ([
-			self[0] * scalar,
-			self[1] * scalar,
-			self[2] * scalar,
-			self[3] * scalar,
-		])
-	}
-}
-
-impl std::ops::Div<Float> for Vector4 {
-	type Output = Self;
-	fn div(self, scalar: Float) -> Self::Output {
-		Self ([
-			self[0] / scalar,
-			self[1] / scalar,
-			self[2] / scalar,
-			self[3] / scalar,
-		])
-	}
-}
-
-impl std::ops::AddAssign for Vector4 {
-	fn add_assign(&mut self, other: Self) {
-		self[0] += other[0];
-		self[1] += other[1];
-		self[2] += other[2];
-		self[3] += other[3];
-	}
-}
-
-impl std::ops::SubAssign for Vector4 {
-	fn sub_assign(&mut self, other: Self) {
-		self[0] -= other[0];
-		self[1] -= other[1];
-		self[2] -= other[2];
-		self[3] -= other[3];
-	}
-}
-
-impl std::ops::MulAssign<Float> for Vector4 {
-	fn mul_assign(&mut self, scalar: Float) {
-		self[0] *= scalar;
-		self[1] *= scalar;
-		self[2] *= scalar;
-		self[3] *= scalar;
-	}
-}
-
-impl std::ops::DivAssign<Float> for Vector4 {
-	fn div_assign(&mut self, scalar: Float) {
-		self[0] /= scalar;
-		self[1] /= scalar;
-		self[2] /= scalar;
-		self[3] /= scalar;
-	}
-}